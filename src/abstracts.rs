@@ -0,0 +1,82 @@
+//! Conversion between plaintext abstracts and the `abstract_inverted_index`
+//! form OpenAlex serves them in (each word mapped to the positions it occurs at).
+
+use std::collections::{BTreeMap, HashMap};
+
+pub type InvertedIndex = HashMap<String, Vec<usize>>;
+
+/// Rebuilds the text of an inverted abstract, words joined by single spaces.
+///
+/// Positions missing from the index are skipped rather than leaving blank
+/// space. When several words claim the same position, the lexicographically
+/// smallest one is kept so the result does not depend on map order.
+pub fn reconstruct(index: &InvertedIndex) -> String {
+    let mut words: BTreeMap<usize, &str> = BTreeMap::new();
+    for (word, positions) in index {
+        for &position in positions {
+            words
+                .entry(position)
+                .and_modify(|current| {
+                    if word.as_str() < *current {
+                        *current = word;
+                    }
+                })
+                .or_insert(word);
+        }
+    }
+    words.into_values().collect::<Vec<_>>().join(" ")
+}
+
+/// Builds the inverted index of `text`, splitting words on whitespace.
+///
+/// Feeding the result to [`reconstruct`] gives back `text` with its
+/// whitespace collapsed to single spaces.
+pub fn invert(text: &str) -> InvertedIndex {
+    let mut index = InvertedIndex::new();
+    for (position, word) in text.split_whitespace().enumerate() {
+        index.entry(word.to_owned()).or_default().push(position);
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(entries: &[(&str, &[usize])]) -> InvertedIndex {
+        entries
+            .iter()
+            .map(|(word, positions)| (word.to_string(), positions.to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn test_reconstruct_orders_words_by_position() {
+        let index = index(&[
+            ("the", &[0, 3]),
+            ("cat", &[1]),
+            ("saw", &[2]),
+            ("dog", &[4]),
+        ]);
+        assert_eq!(reconstruct(&index), "the cat saw the dog");
+        assert_eq!(reconstruct(&InvertedIndex::new()), "");
+    }
+
+    #[test]
+    fn test_reconstruct_tolerates_gaps_and_duplicates() {
+        let index = index(&[("open", &[0]), ("access", &[5, 5]), ("Open", &[0])]);
+        assert_eq!(reconstruct(&index), "Open access");
+    }
+
+    #[test]
+    fn test_invert_round_trips() {
+        let text = "Despite growing interest in Open Access (OA) to scholarly literature, \
+                    there is an unmet need for large-scale, up-to-date, and reproducible \
+                    studies assessing the prevalence and characteristics of OA.";
+        let index = invert(text);
+        assert_eq!(index["(OA)"], vec![6]);
+        assert_eq!(index["and"], vec![18, 24]);
+        assert_eq!(reconstruct(&index), text);
+        assert_eq!(reconstruct(&invert("  spaced \n out ")), "spaced out");
+    }
+}
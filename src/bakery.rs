@@ -3,9 +3,92 @@ use serde_json::{self, Error as SerdeError, Value};
 use std::fs::File;
 use std::io::Read;
 use std::path::PathBuf;
+use thiserror::Error;
+
+/// A decoding failure together with the JSON path of the offending field,
+/// e.g. `authorships[3].institutions[0].lineage`.
+#[derive(Error, Debug)]
+#[error("at `{path}`: {source}")]
+pub struct DecodeError {
+    path: String,
+    segments: Vec<PathSegment>,
+    source: SerdeError,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum PathSegment {
+    Key(String),
+    Index(usize),
+    Opaque,
+}
+
+impl DecodeError {
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn inner(&self) -> &SerdeError {
+        &self.source
+    }
+
+    pub(crate) fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+}
+
+impl From<serde_path_to_error::Error<SerdeError>> for DecodeError {
+    fn from(err: serde_path_to_error::Error<SerdeError>) -> Self {
+        let segments = err
+            .path()
+            .iter()
+            .map(|segment| match segment {
+                serde_path_to_error::Segment::Map { key } => PathSegment::Key(key.clone()),
+                serde_path_to_error::Segment::Seq { index } => PathSegment::Index(*index),
+                _ => PathSegment::Opaque,
+            })
+            .collect();
+        DecodeError {
+            path: err.path().to_string(),
+            segments,
+            source: err.into_inner(),
+        }
+    }
+}
+
+impl From<std::io::Error> for DecodeError {
+    fn from(err: std::io::Error) -> Self {
+        DecodeError {
+            path: ".".to_owned(),
+            segments: Vec::new(),
+            source: SerdeError::io(err),
+        }
+    }
+}
+
+pub(crate) fn from_str<L: DeserializeOwned>(s: &str) -> Result<L, DecodeError> {
+    let mut deserializer = serde_json::Deserializer::from_str(s);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer.end().map_err(|source| DecodeError {
+        path: ".".to_owned(),
+        segments: Vec::new(),
+        source,
+    })?;
+    Ok(value)
+}
+
+pub(crate) fn from_slice<L: DeserializeOwned>(v: &[u8]) -> Result<L, DecodeError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(v);
+    let value = serde_path_to_error::deserialize(&mut deserializer)?;
+    deserializer.end().map_err(|source| DecodeError {
+        path: ".".to_owned(),
+        segments: Vec::new(),
+        source,
+    })?;
+    Ok(value)
+}
 
 pub trait Leavenable {
-    fn leaven<L: DeserializeOwned>(self) -> Result<L, SerdeError>;
+    fn leaven<L: DeserializeOwned>(self) -> Result<L, DecodeError>;
 }
 
 #[derive(Debug)]
@@ -52,41 +135,41 @@ pub trait Deflatable {
 }
 
 impl Leavenable for &str {
-    fn leaven<L: DeserializeOwned>(self) -> Result<L, SerdeError> {
-        serde_json::from_str(self)
+    fn leaven<L: DeserializeOwned>(self) -> Result<L, DecodeError> {
+        from_str(self)
     }
 }
 
 impl Leavenable for String {
-    fn leaven<L: DeserializeOwned>(self) -> Result<L, SerdeError> {
-        serde_json::from_str(&self)
+    fn leaven<L: DeserializeOwned>(self) -> Result<L, DecodeError> {
+        from_str(&self)
     }
 }
 
 impl Leavenable for &Value {
-    fn leaven<L: DeserializeOwned>(self) -> Result<L, SerdeError> {
-        serde_json::from_value(self.clone())
+    fn leaven<L: DeserializeOwned>(self) -> Result<L, DecodeError> {
+        Ok(serde_path_to_error::deserialize(self)?)
     }
 }
 
 impl Leavenable for PathBuf {
-    fn leaven<L: DeserializeOwned>(self) -> Result<L, SerdeError> {
-        let mut file = File::open(self).map_err(SerdeError::io)?;
+    fn leaven<L: DeserializeOwned>(self) -> Result<L, DecodeError> {
+        let mut file = File::open(self)?;
         let mut contents = String::new();
-        file.read_to_string(&mut contents).map_err(SerdeError::io)?;
-        serde_json::from_str(&contents)
+        file.read_to_string(&mut contents)?;
+        from_str(&contents)
     }
 }
 
 impl Leavenable for Vec<u8> {
-    fn leaven<L: DeserializeOwned>(self) -> Result<L, SerdeError> {
-        serde_json::from_slice(&self)
+    fn leaven<L: DeserializeOwned>(self) -> Result<L, DecodeError> {
+        from_slice(&self)
     }
 }
 
 impl Leavenable for &[u8] {
-    fn leaven<L: DeserializeOwned>(self) -> Result<L, SerdeError> {
-        serde_json::from_slice(self)
+    fn leaven<L: DeserializeOwned>(self) -> Result<L, DecodeError> {
+        from_slice(self)
     }
 }
 
@@ -97,7 +180,7 @@ mod tests {
     #[test]
     fn test_deserialize_from_str() {
         let json_str = r#"{"name":"John","age":30}"#;
-        let result: Result<Value, SerdeError> = json_str.leaven();
+        let result: Result<Value, DecodeError> = json_str.leaven();
         assert!(result.is_ok());
         let value = result.unwrap();
         assert_eq!(value["name"], "John");
@@ -107,7 +190,7 @@ mod tests {
     #[test]
     fn test_deserialize_from_string() {
         let json_string = String::from(r#"{"name":"Jane","age":25}"#);
-        let result: Result<Value, SerdeError> = json_string.leaven();
+        let result: Result<Value, DecodeError> = json_string.leaven();
         assert!(result.is_ok());
         let value = result.unwrap();
         assert_eq!(value["name"], "Jane");
@@ -120,10 +203,38 @@ mod tests {
             "name": "Doe",
             "age": 40
         });
-        let result: Result<Value, SerdeError> = json_value.leaven();
+        let result: Result<Value, DecodeError> = json_value.leaven();
         assert!(result.is_ok());
         let value = result.unwrap();
         assert_eq!(value["name"], "Doe");
         assert_eq!(value["age"], 40);
     }
+
+    #[test]
+    fn test_decode_error_reports_path() {
+        #[derive(serde::Deserialize, Debug)]
+        struct Institution {
+            #[allow(dead_code)]
+            lineage: Vec<String>,
+        }
+        #[derive(serde::Deserialize, Debug)]
+        struct Authorship {
+            #[allow(dead_code)]
+            institutions: Vec<Institution>,
+        }
+        #[derive(serde::Deserialize, Debug)]
+        struct Record {
+            #[allow(dead_code)]
+            authorships: Vec<Authorship>,
+        }
+
+        let json = r#"{"authorships":[{"institutions":[]},{"institutions":[{"lineage":"I1"}]}]}"#;
+        let err = json.leaven::<Record>().unwrap_err();
+        assert_eq!(err.path(), "authorships[1].institutions[0].lineage");
+        assert!(err.to_string().contains("expected a sequence"), "{}", err);
+
+        let value: Value = serde_json::from_str(json).unwrap();
+        let err = (&value).leaven::<Record>().unwrap_err();
+        assert_eq!(err.path(), "authorships[1].institutions[0].lineage");
+    }
 }
@@ -0,0 +1,421 @@
+use crate::client::OARSClient;
+use crate::config::OARSConfig;
+use crate::drift::DriftReport;
+use crate::entities::{
+    author::Author, funder::Funder, institution::Institution, publisher::Publisher, source::Source,
+    topic::Topic, work::Work,
+};
+use crate::error::OARSError;
+use crate::ids::{
+    AuthorId, FunderId, InstitutionId, Lookup, PublisherId, SourceId, TopicId, WorkId,
+};
+use crate::query::{GroupBy, Query};
+use crate::response::{BatchResult, Group, GroupByResult, ListResult};
+use crate::traits::*;
+use futures::stream::{BoxStream, StreamExt};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::runtime::{Builder, Runtime};
+
+/// A client for synchronous code, with the same configuration, quota
+/// accounting, retries and errors as [`OARSClient`].
+///
+/// It drives requests on a runtime of its own, so it must not be used from
+/// within an async context.
+#[derive(Clone, Debug)]
+pub struct OARSBlockingClient {
+    inner: OARSClient,
+    runtime: Arc<Runtime>,
+}
+
+impl OARSBlockingClient {
+    /// Fails with [`OARSError::Runtime`] if the runtime cannot be started.
+    pub fn new(config: OARSConfig) -> Result<Self, OARSError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(OARSError::Runtime)?;
+        Ok(Self {
+            inner: OARSClient::new(config),
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    pub fn get_querycount(&self) -> usize {
+        self.inner.get_querycount()
+    }
+
+    pub fn remaining(&self) -> Result<usize, OARSError> {
+        self.inner.remaining()
+    }
+
+    pub fn reset_querycount(&self) {
+        self.inner.reset_querycount()
+    }
+
+    pub fn audit_drift<T: Entity>(
+        &self,
+        query: &Query,
+    ) -> Result<(Vec<T>, DriftReport), OARSError> {
+        self.block_on(self.inner.audit_drift::<T>(query))
+    }
+
+    pub fn audit_drift_pages<T: Entity>(
+        &self,
+        query: &Query,
+        maxpages: usize,
+    ) -> Result<(Vec<T>, DriftReport), OARSError> {
+        self.block_on(self.inner.audit_drift_pages::<T>(query, maxpages))
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    fn iter<T>(&self, stream: BoxStream<'static, Result<T, OARSError>>) -> BlockingIter<T> {
+        BlockingIter {
+            stream,
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+/// Iterator over the records of a paged query, fetching pages as it goes.
+pub struct BlockingIter<T> {
+    stream: BoxStream<'static, Result<T, OARSError>>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Iterator for BlockingIter<T> {
+    type Item = Result<T, OARSError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+impl BlockingAuthorAPIClient for OARSBlockingClient {
+    fn get_author_by_id<I>(&self, author_id: I) -> Result<Author, OARSError>
+    where
+        I: Into<Lookup<AuthorId>>,
+    {
+        self.block_on(self.inner.fetch_one::<Author>(author_id.into()))
+    }
+
+    fn get_authors_by_ids(
+        &self,
+        author_ids: &[AuthorId],
+    ) -> Result<BatchResult<Author, AuthorId>, OARSError> {
+        self.block_on(self.inner.fetch_batch::<Author>(author_ids))
+    }
+
+    fn list_authors(&self, query: &Query) -> Result<ListResult<Author>, OARSError> {
+        self.block_on(self.inner.fetch_list::<Author>(query, None))
+    }
+
+    fn stream_authors(&self, query: &Query) -> BlockingIter<Author> {
+        self.iter(self.inner.stream_list::<Author>(query))
+    }
+
+    fn group_authors(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError> {
+        self.block_on(self.inner.fetch_groups::<Author>(group_by, query, None))
+    }
+
+    fn stream_author_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group> {
+        self.iter(self.inner.stream_groups::<Author>(group_by, query))
+    }
+}
+
+impl BlockingFunderAPIClient for OARSBlockingClient {
+    fn get_funder_by_id<I>(&self, funder_id: I) -> Result<Funder, OARSError>
+    where
+        I: Into<Lookup<FunderId>>,
+    {
+        self.block_on(self.inner.fetch_one::<Funder>(funder_id.into()))
+    }
+
+    fn get_funders_by_ids(
+        &self,
+        funder_ids: &[FunderId],
+    ) -> Result<BatchResult<Funder, FunderId>, OARSError> {
+        self.block_on(self.inner.fetch_batch::<Funder>(funder_ids))
+    }
+
+    fn list_funders(&self, query: &Query) -> Result<ListResult<Funder>, OARSError> {
+        self.block_on(self.inner.fetch_list::<Funder>(query, None))
+    }
+
+    fn stream_funders(&self, query: &Query) -> BlockingIter<Funder> {
+        self.iter(self.inner.stream_list::<Funder>(query))
+    }
+
+    fn group_funders(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError> {
+        self.block_on(self.inner.fetch_groups::<Funder>(group_by, query, None))
+    }
+
+    fn stream_funder_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group> {
+        self.iter(self.inner.stream_groups::<Funder>(group_by, query))
+    }
+}
+
+impl BlockingInstitutionAPIClient for OARSBlockingClient {
+    fn get_institution_by_id<I>(&self, institution_id: I) -> Result<Institution, OARSError>
+    where
+        I: Into<Lookup<InstitutionId>>,
+    {
+        self.block_on(self.inner.fetch_one::<Institution>(institution_id.into()))
+    }
+
+    fn get_institutions_by_ids(
+        &self,
+        institution_ids: &[InstitutionId],
+    ) -> Result<BatchResult<Institution, InstitutionId>, OARSError> {
+        self.block_on(self.inner.fetch_batch::<Institution>(institution_ids))
+    }
+
+    fn list_institutions(&self, query: &Query) -> Result<ListResult<Institution>, OARSError> {
+        self.block_on(self.inner.fetch_list::<Institution>(query, None))
+    }
+
+    fn stream_institutions(&self, query: &Query) -> BlockingIter<Institution> {
+        self.iter(self.inner.stream_list::<Institution>(query))
+    }
+
+    fn group_institutions(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.block_on(
+            self.inner
+                .fetch_groups::<Institution>(group_by, query, None),
+        )
+    }
+
+    fn stream_institution_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group> {
+        self.iter(self.inner.stream_groups::<Institution>(group_by, query))
+    }
+}
+
+impl BlockingPublisherAPIClient for OARSBlockingClient {
+    fn get_publisher_by_id<I>(&self, publisher_id: I) -> Result<Publisher, OARSError>
+    where
+        I: Into<Lookup<PublisherId>>,
+    {
+        self.block_on(self.inner.fetch_one::<Publisher>(publisher_id.into()))
+    }
+
+    fn get_publishers_by_ids(
+        &self,
+        publisher_ids: &[PublisherId],
+    ) -> Result<BatchResult<Publisher, PublisherId>, OARSError> {
+        self.block_on(self.inner.fetch_batch::<Publisher>(publisher_ids))
+    }
+
+    fn list_publishers(&self, query: &Query) -> Result<ListResult<Publisher>, OARSError> {
+        self.block_on(self.inner.fetch_list::<Publisher>(query, None))
+    }
+
+    fn stream_publishers(&self, query: &Query) -> BlockingIter<Publisher> {
+        self.iter(self.inner.stream_list::<Publisher>(query))
+    }
+
+    fn group_publishers(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.block_on(self.inner.fetch_groups::<Publisher>(group_by, query, None))
+    }
+
+    fn stream_publisher_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group> {
+        self.iter(self.inner.stream_groups::<Publisher>(group_by, query))
+    }
+}
+
+impl BlockingSourceAPIClient for OARSBlockingClient {
+    fn get_source_by_id<I>(&self, source_id: I) -> Result<Source, OARSError>
+    where
+        I: Into<Lookup<SourceId>>,
+    {
+        self.block_on(self.inner.fetch_one::<Source>(source_id.into()))
+    }
+
+    fn get_sources_by_ids(
+        &self,
+        source_ids: &[SourceId],
+    ) -> Result<BatchResult<Source, SourceId>, OARSError> {
+        self.block_on(self.inner.fetch_batch::<Source>(source_ids))
+    }
+
+    fn list_sources(&self, query: &Query) -> Result<ListResult<Source>, OARSError> {
+        self.block_on(self.inner.fetch_list::<Source>(query, None))
+    }
+
+    fn stream_sources(&self, query: &Query) -> BlockingIter<Source> {
+        self.iter(self.inner.stream_list::<Source>(query))
+    }
+
+    fn group_sources(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError> {
+        self.block_on(self.inner.fetch_groups::<Source>(group_by, query, None))
+    }
+
+    fn stream_source_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group> {
+        self.iter(self.inner.stream_groups::<Source>(group_by, query))
+    }
+}
+
+impl BlockingTopicAPIClient for OARSBlockingClient {
+    fn get_topic_by_id<I>(&self, topic_id: I) -> Result<Topic, OARSError>
+    where
+        I: Into<Lookup<TopicId>>,
+    {
+        self.block_on(self.inner.fetch_one::<Topic>(topic_id.into()))
+    }
+
+    fn get_topics_by_ids(
+        &self,
+        topic_ids: &[TopicId],
+    ) -> Result<BatchResult<Topic, TopicId>, OARSError> {
+        self.block_on(self.inner.fetch_batch::<Topic>(topic_ids))
+    }
+
+    fn list_topics(&self, query: &Query) -> Result<ListResult<Topic>, OARSError> {
+        self.block_on(self.inner.fetch_list::<Topic>(query, None))
+    }
+
+    fn stream_topics(&self, query: &Query) -> BlockingIter<Topic> {
+        self.iter(self.inner.stream_list::<Topic>(query))
+    }
+
+    fn group_topics(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError> {
+        self.block_on(self.inner.fetch_groups::<Topic>(group_by, query, None))
+    }
+
+    fn stream_topic_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group> {
+        self.iter(self.inner.stream_groups::<Topic>(group_by, query))
+    }
+}
+
+impl BlockingWorkAPIClient for OARSBlockingClient {
+    fn get_work_by_id<I>(&self, work_id: I) -> Result<Work, OARSError>
+    where
+        I: Into<Lookup<WorkId>>,
+    {
+        self.block_on(self.inner.fetch_one::<Work>(work_id.into()))
+    }
+
+    fn get_works_by_ids(
+        &self,
+        work_ids: &[WorkId],
+    ) -> Result<BatchResult<Work, WorkId>, OARSError> {
+        self.block_on(self.inner.fetch_batch::<Work>(work_ids))
+    }
+
+    fn list_works(&self, query: &Query) -> Result<ListResult<Work>, OARSError> {
+        self.block_on(self.inner.fetch_list::<Work>(query, None))
+    }
+
+    fn stream_works(&self, query: &Query) -> BlockingIter<Work> {
+        self.iter(self.inner.stream_list::<Work>(query))
+    }
+
+    fn group_works(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError> {
+        self.block_on(self.inner.fetch_groups::<Work>(group_by, query, None))
+    }
+
+    fn stream_work_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group> {
+        self.iter(self.inner.stream_groups::<Work>(group_by, query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quota::FileQuotaStore;
+    use crate::test_support::cursor_body;
+    use mockito::Matcher;
+
+    #[test]
+    fn test_blocking_get_work_by_id() {
+        let mut server = mockito::Server::new();
+        let _m = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+        let _missing = server.mock("GET", "/works/W1").with_status(404).create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSBlockingClient::new(config).unwrap();
+        let work = client
+            .get_work_by_id(WorkId::new(2741809807))
+            .expect("Failed to get work");
+
+        assert_eq!(work.id(), Some(WorkId::new(2741809807)));
+        assert!(matches!(
+            client.get_work_by_id(WorkId::new(1)),
+            Err(OARSError::NotFound(_))
+        ));
+        assert_eq!(client.get_querycount(), 2);
+    }
+
+    #[test]
+    fn test_blocking_stream_follows_cursor() {
+        let mut server = mockito::Server::new();
+        let _first = server
+            .mock("GET", "/works")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "*".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cursor_body("testdata/work.json", Some("next")))
+            .create();
+        let _second = server
+            .mock("GET", "/works")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "next".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cursor_body("testdata/work.json", None))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSBlockingClient::new(config).unwrap();
+        let works: Vec<Work> = client
+            .stream_works(&Query::new())
+            .collect::<Result<_, _>>()
+            .expect("Failed to stream works");
+
+        assert_eq!(works.len(), 2);
+        assert_eq!(client.get_querycount(), 2);
+    }
+
+    #[test]
+    fn test_blocking_client_shares_quota_store() {
+        let mut server = mockito::Server::new();
+        let _m = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+        let dir = tempfile::tempdir().unwrap();
+        let config = OARSConfig::new()
+            .basepath(server.url())
+            .dailylimit(3)
+            .quotastore(FileQuotaStore::new(dir.path().join("quota")));
+        let client = OARSBlockingClient::new(config.clone()).unwrap();
+        let other = OARSClient::new(config);
+
+        for _ in 0..2 {
+            client.get_work_by_id(WorkId::new(2741809807)).unwrap();
+        }
+        assert_eq!(other.remaining().unwrap(), 1);
+        assert_eq!(client.remaining().unwrap(), 1);
+        client.get_work_by_id(WorkId::new(2741809807)).unwrap();
+        assert!(matches!(
+            client.get_work_by_id(WorkId::new(2741809807)),
+            Err(OARSError::QueryLimitReached)
+        ));
+    }
+}
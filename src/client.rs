@@ -1,23 +1,42 @@
-use crate::config::OARSConfig;
+use crate::bakery;
+use crate::config::{OARSConfig, REDACTED};
+use crate::drift::{DriftAudit, DriftReport};
 use crate::entities::{
     author::Author, funder::Funder, institution::Institution, publisher::Publisher, source::Source,
     topic::Topic, work::Work,
 };
 use crate::error::OARSError;
+use crate::filter::Filter;
+use crate::ids::{
+    AuthorId, FunderId, InstitutionId, Lookup, PublisherId, SourceId, TopicId, WorkId,
+};
+use crate::query::{build_url, GroupBy, Query};
+use crate::quota::{self, MemoryQuotaStore, QuotaStore};
+use crate::ratelimit::RateLimiter;
+use crate::response::{BatchResult, Group, GroupByResult, ListResult};
 use crate::traits::*;
 use async_trait::async_trait;
-use reqwest::Client;
-use std::sync::{
-    atomic::{AtomicUsize, Ordering},
-    Arc,
-};
-use tokio::time::{interval, Interval};
-use tracing::{info, instrument};
+use futures::stream::{self, BoxStream, StreamExt};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Client, StatusCode, Url};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::time::{sleep, Duration};
+use tracing::{info, instrument, warn};
+
+const MAX_BATCH_SIZE: usize = 50;
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
 
 #[derive(Clone, Debug)]
 pub struct OARSClient {
     config: Arc<OARSConfig>,
-    querycount: Arc<AtomicUsize>,
+    quota: Arc<dyn QuotaStore>,
+    ratelimiter: Arc<RateLimiter>,
     client: Client,
 }
 
@@ -25,189 +44,798 @@ impl OARSClient {
     pub fn new(config: OARSConfig) -> Self {
         let config = Arc::new(config);
         let client = Client::new();
-        let oars_client = Self {
+        Self {
             config: config.clone(),
-            querycount: Arc::new(AtomicUsize::new(0)),
+            quota: config
+                .quotastore
+                .clone()
+                .unwrap_or_else(|| Arc::new(MemoryQuotaStore::new())),
+            ratelimiter: Arc::new(RateLimiter::new(config.persecond, config.burstsize)),
             client,
-        };
-
-        // Spawn the reset task
-        let reset_interval = config.resetafter;
-        let cloned_client = oars_client.clone();
-        tokio::spawn(async move {
-            cloned_client.reset_task(interval(reset_interval)).await;
-        });
-
-        oars_client
+        }
     }
 
     #[instrument]
-    fn get_querycount(&self) -> usize {
-        let count = self.querycount.load(Ordering::SeqCst);
+    pub fn get_querycount(&self) -> usize {
+        let count = match self.quota.used(self.quota_window()) {
+            Ok(count) => count,
+            Err(error) => {
+                warn!(error = %error, "could not read the query count");
+                0
+            }
+        };
         if self.config.logornot {
             info!("Query Count: {}", count);
         }
         count
     }
 
+    /// Queries left in the current quota window.
+    pub fn remaining(&self) -> Result<usize, OARSError> {
+        let used = self.quota.used(self.quota_window())?;
+        Ok(self.config.dailylimit.saturating_sub(used))
+    }
+
+    /// Fetches one page of `query` and decodes it in audit mode, returning the
+    /// records that could be decoded along with the drift found in the page.
+    pub async fn audit_drift<T: Entity>(
+        &self,
+        query: &Query,
+    ) -> Result<(Vec<T>, DriftReport), OARSError> {
+        let pairs = Self::list_pairs::<T>(query, None)?;
+        let page: ListResult<Value> = self.fetch_json(T::ENDPOINT, &pairs).await?;
+        let mut audit = DriftAudit::new();
+        let records = page
+            .results
+            .iter()
+            .filter_map(|value| audit.leaven::<T, _>(value).ok())
+            .collect();
+        Ok((records, audit.report()))
+    }
+
+    /// Like [`OARSClient::audit_drift`], but follows the cursor through up to
+    /// `maxpages` pages of `query` and reports the drift found across all of them.
+    pub async fn audit_drift_pages<T: Entity>(
+        &self,
+        query: &Query,
+        maxpages: usize,
+    ) -> Result<(Vec<T>, DriftReport), OARSError> {
+        let mut audit = DriftAudit::new();
+        let mut records = Vec::new();
+        let mut cursor = Some("*".to_owned());
+        for _ in 0..maxpages {
+            let Some(current) = cursor.take() else {
+                break;
+            };
+            let pairs = Self::list_pairs::<T>(query, Some(&current))?;
+            let page: ListResult<Value> = self.fetch_json(T::ENDPOINT, &pairs).await?;
+            if !page.results.is_empty() {
+                cursor = page.meta.next_cursor;
+            }
+            records.extend(
+                page.results
+                    .iter()
+                    .filter_map(|value| audit.leaven::<T, _>(value).ok()),
+            );
+        }
+        Ok((records, audit.report()))
+    }
+
+    fn quota_window(&self) -> u64 {
+        quota::window(SystemTime::now(), self.config.resetafter)
+    }
+
     #[instrument]
     fn bump_querycount(&self, increment: Option<usize>) -> Result<(), OARSError> {
         let increment = increment.unwrap_or(1);
-        let current_count = self.querycount.load(Ordering::SeqCst);
-        if current_count + increment > self.config.dailylimit {
-            return Err(OARSError::QueryLimitReached);
-        }
-
-        self.querycount.fetch_add(increment, Ordering::SeqCst);
+        let count = self
+            .quota
+            .consume(self.quota_window(), increment, self.config.dailylimit)?;
         if self.config.logornot {
-            info!(
-                "Query Count: {} (+{})",
-                current_count + increment,
-                increment
-            );
+            info!("Query Count: {} (+{})", count, increment);
         }
         Ok(())
     }
 
+    // Counts one query from async code. The store may block, e.g. on the
+    // lock of a `FileQuotaStore` held by another process, so it is called on
+    // the blocking pool rather than on the runtime's worker.
+    async fn consume_query(&self) -> Result<(), OARSError> {
+        let client = self.clone();
+        match tokio::task::spawn_blocking(move || client.bump_querycount(None)).await {
+            Ok(result) => result,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(_) => Err(OARSError::Unknown),
+        }
+    }
+
+    /// Clears the count for the current quota window. Counts otherwise start
+    /// over on their own once a new window begins.
     #[instrument]
-    fn reset_querycount(&self) {
-        self.querycount.store(0, Ordering::SeqCst);
+    pub fn reset_querycount(&self) {
+        if let Err(error) = self.quota.reset(self.quota_window()) {
+            warn!(error = %error, "could not reset the query count");
+            return;
+        }
         if self.config.logornot {
             info!("Query Count reset to 0");
         }
     }
 
-    async fn reset_task(&self, mut interval: Interval) {
+    // Every request the client issues is a GET, so any failure classified as
+    // retryable can be safely repeated.
+    async fn fetch_json<R: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        pairs: &[(&str, String)],
+    ) -> Result<R, OARSError> {
+        let mut pairs = pairs.to_vec();
+        pairs.extend(self.config.identity_pairs());
+        let url = build_url(&self.config.basepath, endpoint, &pairs)?;
+
+        let mut attempts = 0;
+        let mut waited = Duration::ZERO;
         loop {
-            interval.tick().await;
-            self.reset_querycount();
+            self.consume_query().await?;
+            attempts += 1;
+            self.ratelimiter.acquire().await;
+
+            let error = match self.send_once::<R>(&url, endpoint).await {
+                Ok(response) => {
+                    self.ratelimiter.recover();
+                    return Ok(response);
+                }
+                Err(error) => error,
+            };
+            if let OARSError::RateLimited { .. } = error {
+                self.ratelimiter.throttle();
+                if self.config.logornot {
+                    warn!(
+                        persecond = self.ratelimiter.rate(),
+                        "rate limited on {}, slowing down", endpoint
+                    );
+                }
+            }
+            // A server asking us to hold off for longer than we would ever back
+            // off on our own is better reported than slept through.
+            let retry_after = error.retry_after();
+            let wait_too_long = retry_after.is_some_and(|delay| delay > MAX_BACKOFF);
+            if !error.is_retryable() || attempts > self.config.maxretries || wait_too_long {
+                if attempts == 1 {
+                    return Err(error);
+                }
+                if self.config.logornot {
+                    warn!(
+                        attempts,
+                        waited_ms = waited.as_millis() as u64,
+                        error = %error,
+                        "giving up on {}",
+                        endpoint
+                    );
+                }
+                return Err(OARSError::Retried {
+                    attempts,
+                    waited,
+                    source: Box::new(error),
+                });
+            }
+
+            let delay =
+                retry_after.unwrap_or_else(|| backoff_delay(self.config.politeness, attempts));
+            if self.config.logornot {
+                warn!(
+                    attempt = attempts,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %error,
+                    "retrying {}",
+                    endpoint
+                );
+            }
+            sleep(delay).await;
+            waited += delay;
+        }
+    }
+
+    async fn send_once<R: DeserializeOwned>(
+        &self,
+        url: &Url,
+        endpoint: &str,
+    ) -> Result<R, OARSError> {
+        let response = self
+            .client
+            .get(url.clone())
+            .header("User-Agent", self.config.user_agent())
+            .send()
+            .await
+            .map_err(redact_url)?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response.text().await.map_err(redact_url)?;
+
+        match status {
+            status if status.is_success() => {
+                bakery::from_str(&body).map_err(|source| OARSError::Deserialization {
+                    snippet: snippet(&body, source.inner()),
+                    source,
+                })
+            }
+            StatusCode::NOT_FOUND => Err(OARSError::NotFound(endpoint.to_owned())),
+            StatusCode::TOO_MANY_REQUESTS => Err(OARSError::RateLimited { retry_after }),
+            status if status.is_server_error() => Err(OARSError::ServerError {
+                status: status.as_u16(),
+                retry_after,
+            }),
+            status => {
+                let ErrorBody { error, message } = serde_json::from_str(&body).unwrap_or_default();
+                let message = message.unwrap_or(body);
+                match status {
+                    StatusCode::UNAUTHORIZED => Err(OARSError::Unauthorized(message)),
+                    StatusCode::FORBIDDEN => Err(OARSError::Forbidden(message)),
+                    status => Err(OARSError::BadRequest {
+                        error: error
+                            .or_else(|| status.canonical_reason().map(str::to_owned))
+                            .unwrap_or_else(|| status.to_string()),
+                        message,
+                    }),
+                }
+            }
+        }
+    }
+
+    pub(crate) async fn fetch_one<T: Entity>(&self, lookup: Lookup<T::Id>) -> Result<T, OARSError> {
+        if let Lookup::External(id) = &lookup {
+            if !T::EXTERNAL_NAMESPACES.contains(&id.namespace()) {
+                return Err(OARSError::InvalidId(format!(
+                    "{} cannot be looked up by {} IDs",
+                    T::ENDPOINT,
+                    id.namespace()
+                )));
+            }
+        }
+        let endpoint = format!("{}/{}", T::ENDPOINT, lookup.path_segment());
+        self.fetch_json(&endpoint, &[]).await
+    }
+
+    fn list_pairs<T: Entity>(
+        query: &Query,
+        cursor: Option<&str>,
+    ) -> Result<Vec<(&'static str, String)>, OARSError> {
+        query.validate()?;
+        query.validate_select(T::FIELDS)?;
+        let mut pairs = match cursor {
+            Some(_) => query.cursor_query()?.to_pairs(),
+            None => query.to_pairs(),
+        };
+        if let Some(cursor) = cursor {
+            pairs.push(("cursor", cursor.to_owned()));
+        }
+        Ok(pairs)
+    }
+
+    pub(crate) async fn fetch_list<T: Entity>(
+        &self,
+        query: &Query,
+        cursor: Option<&str>,
+    ) -> Result<ListResult<T>, OARSError> {
+        let pairs = Self::list_pairs::<T>(query, cursor)?;
+        self.fetch_json(T::ENDPOINT, &pairs).await
+    }
+
+    pub(crate) async fn fetch_batch<T: Entity>(
+        &self,
+        ids: &[T::Id],
+    ) -> Result<BatchResult<T, T::Id>, OARSError> {
+        let mut requested: Vec<T::Id> = Vec::new();
+        for id in ids {
+            if !requested.contains(id) {
+                requested.push(*id);
+            }
+        }
+
+        let mut found: HashMap<T::Id, T> = HashMap::new();
+        let mut merged = HashMap::new();
+        for chunk in requested.chunks(MAX_BATCH_SIZE) {
+            let query = Query::new()
+                .filter(Filter::new().any_of("openalex_id", chunk))
+                .per_page(MAX_BATCH_SIZE);
+            let page = self.fetch_list::<T>(&query, None).await?;
+            for record in page.results {
+                if let Some(id) = record.openalex_id().copied() {
+                    if chunk.contains(&id) {
+                        found.insert(id, record);
+                    }
+                }
+            }
+            // An ID left over may have been merged into another record, which
+            // the filter returns (if at all) without saying which ID it answers.
+            // The single record endpoint follows merges, so look each one up.
+            let leftovers: Vec<T::Id> = chunk
+                .iter()
+                .filter(|id| !found.contains_key(id))
+                .copied()
+                .collect();
+            for id in leftovers {
+                match self.fetch_one::<T>(Lookup::OpenAlex(id)).await {
+                    Ok(record) => {
+                        if let Some(&canonical) = record.openalex_id().filter(|&&c| c != id) {
+                            merged.insert(id, canonical);
+                        }
+                        found.insert(id, record);
+                    }
+                    Err(OARSError::NotFound(_)) => {}
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+
+        let missing = requested
+            .into_iter()
+            .filter(|id| !found.contains_key(id))
+            .collect();
+        let last: HashMap<T::Id, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        let results = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| match last[id] == i {
+                true => (*id, found.remove(id)),
+                false => (*id, found.get(id).cloned()),
+            })
+            .collect();
+        Ok(BatchResult {
+            results,
+            missing,
+            merged,
+        })
+    }
+
+    pub(crate) async fn fetch_groups<T: Entity>(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+        cursor: Option<&str>,
+    ) -> Result<GroupByResult, OARSError> {
+        query.validate()?;
+        query.validate_group_by()?;
+        let mut pairs = match cursor {
+            Some(_) => query.cursor_query()?.to_pairs(),
+            None => query.to_pairs(),
+        };
+        pairs.push(("group_by", group_by.to_string()));
+        if let Some(cursor) = cursor {
+            pairs.push(("cursor", cursor.to_owned()));
+        }
+        self.fetch_json(T::ENDPOINT, &pairs).await
+    }
+
+    fn cursor_stream<T, F, Fut>(&self, fetch_page: F) -> BoxStream<'static, Result<T, OARSError>>
+    where
+        T: Send + 'static,
+        F: Fn(OARSClient, String) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(Vec<T>, Option<String>), OARSError>> + Send + 'static,
+    {
+        let state = CursorState {
+            client: self.clone(),
+            cursor: Some("*".to_owned()),
+            buffer: VecDeque::new(),
+            fetch_page,
+        };
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.buffer.pop_front() {
+                    return Some((Ok(item), state));
+                }
+                let cursor = state.cursor.take()?;
+                match (state.fetch_page)(state.client.clone(), cursor).await {
+                    Ok((items, next_cursor)) => {
+                        if !items.is_empty() {
+                            state.cursor = next_cursor;
+                        }
+                        state.buffer.extend(items);
+                    }
+                    Err(err) => return Some((Err(err), state)),
+                }
+            }
+        })
+        .boxed()
+    }
+
+    pub(crate) fn stream_list<T: Entity>(
+        &self,
+        query: &Query,
+    ) -> BoxStream<'static, Result<T, OARSError>> {
+        let query = query.clone();
+        self.cursor_stream(move |client, cursor| {
+            let query = query.clone();
+            async move {
+                let page = client.fetch_list::<T>(&query, Some(&cursor)).await?;
+                Ok((page.results, page.meta.next_cursor))
+            }
+        })
+    }
+
+    pub(crate) fn stream_groups<T: Entity>(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        let group_by = group_by.clone();
+        let query = query.clone();
+        self.cursor_stream(move |client, cursor| {
+            let group_by = group_by.clone();
+            let query = query.clone();
+            async move {
+                let page = client
+                    .fetch_groups::<T>(&group_by, &query, Some(&cursor))
+                    .await?;
+                Ok((page.group_by, page.meta.next_cursor))
+            }
+        })
+    }
+}
+
+#[derive(Default, Deserialize)]
+struct ErrorBody {
+    error: Option<String>,
+    message: Option<String>,
+}
+
+// Up to 60 characters either side of where decoding failed.
+fn snippet(body: &str, err: &serde_json::Error) -> String {
+    let mut offset = body
+        .split_inclusive('\n')
+        .take(err.line().saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + err.column().saturating_sub(1);
+    offset = offset.min(body.len());
+    while !body.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let start = body[..offset]
+        .char_indices()
+        .rev()
+        .nth(59)
+        .map_or(0, |(i, _)| i);
+    body[start..].chars().take(120).collect()
+}
+
+// Exponential backoff from `politeness` seconds, doubled per attempt and
+// capped, with the upper half of each step jittered.
+fn backoff_delay(politeness: f64, attempt: usize) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16) as i32;
+    let step = (politeness.max(0.0) * 2f64.powi(exponent)).min(MAX_BACKOFF.as_secs_f64());
+    Duration::from_secs_f64(step / 2.0 + step / 2.0 * fastrand::f64())
+}
+
+// Network errors carry the request URL, which includes the caller's email and
+// API key; blank those out before the error is logged or returned.
+fn redact_url(mut error: reqwest::Error) -> reqwest::Error {
+    if let Some(url) = error.url_mut() {
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(key, value)| match key.as_ref() {
+                "mailto" | "api_key" => (key.into_owned(), REDACTED.to_owned()),
+                _ => (key.into_owned(), value.into_owned()),
+            })
+            .collect();
+        if !pairs.is_empty() {
+            url.query_pairs_mut().clear().extend_pairs(pairs);
         }
     }
+    error
+}
+
+// `Retry-After` is either a number of seconds or an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    match value.parse::<u64>() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => httpdate::parse_http_date(value)
+            .ok()
+            .map(|date| date.duration_since(SystemTime::now()).unwrap_or_default()),
+    }
+}
+
+struct CursorState<T, F> {
+    client: OARSClient,
+    cursor: Option<String>,
+    buffer: VecDeque<T>,
+    fetch_page: F,
 }
 
 #[async_trait]
 impl WorkAPIClient for OARSClient {
-    async fn get_work_by_id(&self, work_id: &str) -> Result<Work, OARSError> {
-        self.bump_querycount(None)?;
+    async fn get_work_by_id<I>(&self, work_id: I) -> Result<Work, OARSError>
+    where
+        I: Into<Lookup<WorkId>> + Send,
+    {
+        self.fetch_one::<Work>(work_id.into()).await
+    }
 
-        let url = format!("{}/works/{}", self.config.basepath, work_id);
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", &self.config.useragent)
-            .send()
-            .await?
-            .json::<Work>()
-            .await?;
-        Ok(response)
+    async fn get_works_by_ids(
+        &self,
+        work_ids: &[WorkId],
+    ) -> Result<BatchResult<Work, WorkId>, OARSError> {
+        self.fetch_batch::<Work>(work_ids).await
+    }
+
+    async fn list_works(&self, query: &Query) -> Result<ListResult<Work>, OARSError> {
+        self.fetch_list::<Work>(query, None).await
+    }
+
+    fn stream_works(&self, query: &Query) -> BoxStream<'static, Result<Work, OARSError>> {
+        self.stream_list::<Work>(query)
+    }
+
+    async fn group_works(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.fetch_groups::<Work>(group_by, query, None).await
+    }
+
+    fn stream_work_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        self.stream_groups::<Work>(group_by, query)
     }
 }
 
 #[async_trait]
 impl AuthorAPIClient for OARSClient {
-    async fn get_author_by_id(&self, author_id: &str) -> Result<Author, OARSError> {
-        self.bump_querycount(None)?;
+    async fn get_author_by_id<I>(&self, author_id: I) -> Result<Author, OARSError>
+    where
+        I: Into<Lookup<AuthorId>> + Send,
+    {
+        self.fetch_one::<Author>(author_id.into()).await
+    }
 
-        let url = format!("{}/authors/{}", self.config.basepath, author_id);
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", &self.config.useragent)
-            .send()
-            .await?
-            .json::<Author>()
-            .await?;
-        Ok(response)
+    async fn get_authors_by_ids(
+        &self,
+        author_ids: &[AuthorId],
+    ) -> Result<BatchResult<Author, AuthorId>, OARSError> {
+        self.fetch_batch::<Author>(author_ids).await
+    }
+
+    async fn list_authors(&self, query: &Query) -> Result<ListResult<Author>, OARSError> {
+        self.fetch_list::<Author>(query, None).await
+    }
+
+    fn stream_authors(&self, query: &Query) -> BoxStream<'static, Result<Author, OARSError>> {
+        self.stream_list::<Author>(query)
+    }
+
+    async fn group_authors(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.fetch_groups::<Author>(group_by, query, None).await
+    }
+
+    fn stream_author_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        self.stream_groups::<Author>(group_by, query)
     }
 }
 
 #[async_trait]
 impl FunderAPIClient for OARSClient {
-    async fn get_funder_by_id(&self, funder_id: &str) -> Result<Funder, OARSError> {
-        self.bump_querycount(None)?;
+    async fn get_funder_by_id<I>(&self, funder_id: I) -> Result<Funder, OARSError>
+    where
+        I: Into<Lookup<FunderId>> + Send,
+    {
+        self.fetch_one::<Funder>(funder_id.into()).await
+    }
 
-        let url = format!("{}/funders/{}", self.config.basepath, funder_id);
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", &self.config.useragent)
-            .send()
-            .await?
-            .json::<Funder>()
-            .await?;
-        Ok(response)
+    async fn get_funders_by_ids(
+        &self,
+        funder_ids: &[FunderId],
+    ) -> Result<BatchResult<Funder, FunderId>, OARSError> {
+        self.fetch_batch::<Funder>(funder_ids).await
+    }
+
+    async fn list_funders(&self, query: &Query) -> Result<ListResult<Funder>, OARSError> {
+        self.fetch_list::<Funder>(query, None).await
+    }
+
+    fn stream_funders(&self, query: &Query) -> BoxStream<'static, Result<Funder, OARSError>> {
+        self.stream_list::<Funder>(query)
+    }
+
+    async fn group_funders(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.fetch_groups::<Funder>(group_by, query, None).await
+    }
+
+    fn stream_funder_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        self.stream_groups::<Funder>(group_by, query)
     }
 }
 
 #[async_trait]
 impl InstitutionAPIClient for OARSClient {
-    async fn get_institution_by_id(&self, institution_id: &str) -> Result<Institution, OARSError> {
-        self.bump_querycount(None)?;
+    async fn get_institution_by_id<I>(&self, institution_id: I) -> Result<Institution, OARSError>
+    where
+        I: Into<Lookup<InstitutionId>> + Send,
+    {
+        self.fetch_one::<Institution>(institution_id.into()).await
+    }
 
-        let url = format!("{}/institutions/{}", self.config.basepath, institution_id);
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", &self.config.useragent)
-            .send()
-            .await?
-            .json::<Institution>()
-            .await?;
-        Ok(response)
+    async fn get_institutions_by_ids(
+        &self,
+        institution_ids: &[InstitutionId],
+    ) -> Result<BatchResult<Institution, InstitutionId>, OARSError> {
+        self.fetch_batch::<Institution>(institution_ids).await
+    }
+
+    async fn list_institutions(&self, query: &Query) -> Result<ListResult<Institution>, OARSError> {
+        self.fetch_list::<Institution>(query, None).await
+    }
+
+    fn stream_institutions(
+        &self,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Institution, OARSError>> {
+        self.stream_list::<Institution>(query)
+    }
+
+    async fn group_institutions(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.fetch_groups::<Institution>(group_by, query, None)
+            .await
+    }
+
+    fn stream_institution_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        self.stream_groups::<Institution>(group_by, query)
     }
 }
 
 #[async_trait]
 impl PublisherAPIClient for OARSClient {
-    async fn get_publisher_by_id(&self, publisher_id: &str) -> Result<Publisher, OARSError> {
-        self.bump_querycount(None)?;
+    async fn get_publisher_by_id<I>(&self, publisher_id: I) -> Result<Publisher, OARSError>
+    where
+        I: Into<Lookup<PublisherId>> + Send,
+    {
+        self.fetch_one::<Publisher>(publisher_id.into()).await
+    }
 
-        let url = format!("{}/publishers/{}", self.config.basepath, publisher_id);
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", &self.config.useragent)
-            .send()
-            .await?
-            .json::<Publisher>()
-            .await?;
-        Ok(response)
+    async fn get_publishers_by_ids(
+        &self,
+        publisher_ids: &[PublisherId],
+    ) -> Result<BatchResult<Publisher, PublisherId>, OARSError> {
+        self.fetch_batch::<Publisher>(publisher_ids).await
+    }
+
+    async fn list_publishers(&self, query: &Query) -> Result<ListResult<Publisher>, OARSError> {
+        self.fetch_list::<Publisher>(query, None).await
+    }
+
+    fn stream_publishers(&self, query: &Query) -> BoxStream<'static, Result<Publisher, OARSError>> {
+        self.stream_list::<Publisher>(query)
+    }
+
+    async fn group_publishers(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.fetch_groups::<Publisher>(group_by, query, None).await
+    }
+
+    fn stream_publisher_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        self.stream_groups::<Publisher>(group_by, query)
     }
 }
 
 #[async_trait]
 impl SourceAPIClient for OARSClient {
-    async fn get_source_by_id(&self, source_id: &str) -> Result<Source, OARSError> {
-        self.bump_querycount(None)?;
+    async fn get_source_by_id<I>(&self, source_id: I) -> Result<Source, OARSError>
+    where
+        I: Into<Lookup<SourceId>> + Send,
+    {
+        self.fetch_one::<Source>(source_id.into()).await
+    }
 
-        let url = format!("{}/sources/{}", self.config.basepath, source_id);
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", &self.config.useragent)
-            .send()
-            .await?
-            .json::<Source>()
-            .await?;
-        Ok(response)
+    async fn get_sources_by_ids(
+        &self,
+        source_ids: &[SourceId],
+    ) -> Result<BatchResult<Source, SourceId>, OARSError> {
+        self.fetch_batch::<Source>(source_ids).await
+    }
+
+    async fn list_sources(&self, query: &Query) -> Result<ListResult<Source>, OARSError> {
+        self.fetch_list::<Source>(query, None).await
+    }
+
+    fn stream_sources(&self, query: &Query) -> BoxStream<'static, Result<Source, OARSError>> {
+        self.stream_list::<Source>(query)
+    }
+
+    async fn group_sources(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.fetch_groups::<Source>(group_by, query, None).await
+    }
+
+    fn stream_source_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        self.stream_groups::<Source>(group_by, query)
     }
 }
 
 #[async_trait]
 impl TopicAPIClient for OARSClient {
-    async fn get_topic_by_id(&self, topic_id: &str) -> Result<Topic, OARSError> {
-        self.bump_querycount(None)?;
+    async fn get_topic_by_id<I>(&self, topic_id: I) -> Result<Topic, OARSError>
+    where
+        I: Into<Lookup<TopicId>> + Send,
+    {
+        self.fetch_one::<Topic>(topic_id.into()).await
+    }
 
-        let url = format!("{}/topics/{}", self.config.basepath, topic_id);
-        let response = self
-            .client
-            .get(&url)
-            .header("User-Agent", &self.config.useragent)
-            .send()
-            .await?
-            .json::<Topic>()
-            .await?;
-        Ok(response)
+    async fn get_topics_by_ids(
+        &self,
+        topic_ids: &[TopicId],
+    ) -> Result<BatchResult<Topic, TopicId>, OARSError> {
+        self.fetch_batch::<Topic>(topic_ids).await
+    }
+
+    async fn list_topics(&self, query: &Query) -> Result<ListResult<Topic>, OARSError> {
+        self.fetch_list::<Topic>(query, None).await
+    }
+
+    fn stream_topics(&self, query: &Query) -> BoxStream<'static, Result<Topic, OARSError>> {
+        self.stream_list::<Topic>(query)
+    }
+
+    async fn group_topics(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError> {
+        self.fetch_groups::<Topic>(group_by, query, None).await
+    }
+
+    fn stream_topic_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>> {
+        self.stream_groups::<Topic>(group_by, query)
     }
 }
 
@@ -215,6 +843,15 @@ impl TopicAPIClient for OARSClient {
 mod tests {
     use super::*;
     use crate::config::OARSConfig;
+    use crate::filter::Filter;
+    use crate::ids::{
+        AuthorId, ExternalId, FunderId, InstitutionId, PublisherId, SourceId, TopicId, WorkId,
+    };
+    use crate::quota::FileQuotaStore;
+    use crate::search::{Search, SearchField};
+    use crate::test_support::{cursor_body, list_body};
+    use mockito::Matcher;
+    use tokio::time::Instant;
 
     #[tokio::test]
     async fn increment_querycount() {
@@ -241,6 +878,82 @@ mod tests {
         assert_eq!(client.get_querycount(), 0);
     }
 
+    #[tokio::test]
+    async fn querycount_survives_restarts_with_file_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota");
+        let config = || {
+            OARSConfig::new()
+                .dailylimit(10)
+                .quotastore(FileQuotaStore::new(&path))
+        };
+
+        let first = OARSClient::new(config());
+        first.bump_querycount(Some(4)).unwrap();
+        drop(first);
+
+        let second = OARSClient::new(config());
+        assert_eq!(second.get_querycount(), 4);
+        assert_eq!(second.remaining().unwrap(), 6);
+        assert!(second.bump_querycount(Some(7)).is_err());
+        second.bump_querycount(Some(6)).unwrap();
+        assert_eq!(second.remaining().unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn locked_quota_file_does_not_block_the_runtime() {
+        use fs2::FileExt;
+
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota");
+        let held = std::fs::File::create(&path).unwrap();
+        held.lock_exclusive().unwrap();
+        let (unlock, unlocked) = std::sync::mpsc::channel::<()>();
+        let release = std::thread::spawn(move || {
+            let _ = unlocked.recv_timeout(Duration::from_secs(2));
+            held.unlock().unwrap();
+        });
+
+        let config = OARSConfig::new()
+            .basepath(server.url())
+            .quotastore(FileQuotaStore::new(&path));
+        let client = OARSClient::new(config);
+        let work_id: WorkId = "W2741809807".parse().unwrap();
+        let fetch = tokio::spawn(async move { client.get_work_by_id(work_id).await });
+
+        // The test runs on a single thread, so this sleep only ends on time
+        // if the fetch waits for the lock without holding that thread.
+        let started = Instant::now();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(!fetch.is_finished());
+
+        unlock.send(()).unwrap();
+        release.join().unwrap();
+        fetch.await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn client_works_outside_a_runtime() {
+        let config = OARSConfig::new().dailylimit(1);
+        let client = OARSClient::new(config);
+        let clone = client.clone();
+        client.bump_querycount(None).unwrap();
+        assert!(clone.bump_querycount(None).is_err());
+
+        drop(clone);
+        assert_eq!(Arc::strong_count(&client.config), 1);
+        assert_eq!(Arc::strong_count(&client.quota), 1);
+    }
+
     #[tokio::test]
     async fn query_limit() {
         let config = OARSConfig::new().dailylimit(1000);
@@ -264,7 +977,7 @@ mod tests {
 
         let config = OARSConfig::new().basepath(server.url());
         let client = OARSClient::new(config);
-        let work_id = "W2741809807";
+        let work_id: WorkId = "W2741809807".parse().unwrap();
         let work = client
             .get_work_by_id(work_id)
             .await
@@ -285,7 +998,7 @@ mod tests {
 
         let config = OARSConfig::new().basepath(server.url());
         let client = OARSClient::new(config);
-        let author_id = "A5023888391";
+        let author_id: AuthorId = "A5023888391".parse().unwrap();
         let author = client
             .get_author_by_id(author_id)
             .await
@@ -306,7 +1019,7 @@ mod tests {
 
         let config = OARSConfig::new().basepath(server.url());
         let client = OARSClient::new(config);
-        let funder_id = "F4320332161";
+        let funder_id: FunderId = "F4320332161".parse().unwrap();
         let funder = client
             .get_funder_by_id(funder_id)
             .await
@@ -327,7 +1040,7 @@ mod tests {
 
         let config = OARSConfig::new().basepath(server.url());
         let client = OARSClient::new(config);
-        let institution_id = "I27837315";
+        let institution_id: InstitutionId = "I27837315".parse().unwrap();
         let institution = client
             .get_institution_by_id(institution_id)
             .await
@@ -348,7 +1061,7 @@ mod tests {
 
         let config = OARSConfig::new().basepath(server.url());
         let client = OARSClient::new(config);
-        let publisher_id = "P4310319965";
+        let publisher_id: PublisherId = "P4310319965".parse().unwrap();
         let publisher = client
             .get_publisher_by_id(publisher_id)
             .await
@@ -369,7 +1082,7 @@ mod tests {
 
         let config = OARSConfig::new().basepath(server.url());
         let client = OARSClient::new(config);
-        let source_id = "S137773608";
+        let source_id: SourceId = "S137773608".parse().unwrap();
         let source = client
             .get_source_by_id(source_id)
             .await
@@ -390,7 +1103,7 @@ mod tests {
 
         let config = OARSConfig::new().basepath(server.url());
         let client = OARSClient::new(config);
-        let topic_id = "T11636";
+        let topic_id: TopicId = "T11636".parse().unwrap();
         let topic = client
             .get_topic_by_id(topic_id)
             .await
@@ -398,4 +1111,1084 @@ mod tests {
 
         crate::check_entity_against_json!(Topic, "testdata/topic.json", topic);
     }
+
+    #[tokio::test]
+    async fn test_list_works() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::UrlEncoded(
+                "filter".into(),
+                "publication_year:>2016,type:!paratext,institutions.country_code:fr|gb".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(list_body("testdata/work.json"))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let filter = Filter::new()
+            .greater_than("publication_year", 2016)
+            .not_equals("type", "paratext")
+            .any_of("institutions.country_code", ["fr", "gb"]);
+        let works = client
+            .list_works(&Query::from(filter))
+            .await
+            .expect("Failed to list works");
+
+        assert_eq!(works.meta.count, Some(1));
+        assert_eq!(works.results.len(), 1);
+        crate::check_entity_against_json!(Work, "testdata/work.json", works.results[0]);
+    }
+
+    #[tokio::test]
+    async fn test_list_authors_without_filter() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/authors")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(list_body("testdata/author.json"))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let authors = client
+            .list_authors(&Query::new())
+            .await
+            .expect("Failed to list authors");
+
+        assert_eq!(authors.meta.per_page, Some(25));
+        crate::check_entity_against_json!(Author, "testdata/author.json", authors.results[0]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_works_follows_cursor() {
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/works")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("filter".into(), "publication_year:2017".into()),
+                Matcher::UrlEncoded("cursor".into(), "*".into()),
+                Matcher::UrlEncoded("per_page".into(), "200".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cursor_body(
+                "testdata/work.json",
+                Some("IlsxNjA5MzcyODAwMDAwXSI="),
+            ))
+            .expect(1)
+            .create();
+        let second = server
+            .mock("GET", "/works")
+            .match_query(Matcher::UrlEncoded(
+                "cursor".into(),
+                "IlsxNjA5MzcyODAwMDAwXSI=".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cursor_body("testdata/work.json", None))
+            .expect(1)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let filter = Filter::new().equals("publication_year", 2017);
+        let works: Vec<Result<Work, OARSError>> =
+            client.stream_works(&filter.into()).collect().await;
+
+        assert_eq!(works.len(), 2);
+        assert!(works.iter().all(|work| work.is_ok()));
+        assert_eq!(client.get_querycount(), 2);
+        first.assert();
+        second.assert();
+    }
+
+    #[tokio::test]
+    async fn test_stream_take_does_not_overfetch() {
+        let mut server = mockito::Server::new_async().await;
+        let _first = server
+            .mock("GET", "/topics")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "*".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cursor_body("testdata/topic.json", Some("next")))
+            .create();
+        let second = server
+            .mock("GET", "/topics")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "next".into()))
+            .expect(0)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let topics: Vec<Result<Topic, OARSError>> =
+            client.stream_topics(&Query::new()).take(1).collect().await;
+
+        assert_eq!(topics.len(), 1);
+        assert_eq!(client.get_querycount(), 1);
+        second.assert();
+    }
+
+    #[tokio::test]
+    async fn test_identity_is_sent_on_every_request() {
+        let mut server = mockito::Server::new_async().await;
+        let identity = || {
+            Matcher::AllOf(vec![
+                Matcher::UrlEncoded("mailto".into(), "test@example.com".into()),
+                Matcher::UrlEncoded("api_key".into(), "testapikey".into()),
+            ])
+        };
+        let by_id = server
+            .mock("GET", "/works/W2741809807")
+            .match_query(identity())
+            .match_header(
+                "user-agent",
+                Matcher::Regex(r"\(mailto:test@example\.com\)$".into()),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+        let paged = server
+            .mock("GET", "/topics")
+            .match_query(Matcher::AllOf(vec![
+                identity(),
+                Matcher::UrlEncoded("cursor".into(), "*".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cursor_body("testdata/topic.json", None))
+            .create();
+
+        let config = OARSConfig::new()
+            .basepath(server.url())
+            .email("test@example.com".to_string())
+            .apikey("testapikey".to_string());
+        let client = OARSClient::new(config);
+        client
+            .get_work_by_id(WorkId::new(2741809807))
+            .await
+            .expect("Failed to get work with identity");
+        let topics: Vec<Result<Topic, OARSError>> =
+            client.stream_topics(&Query::new()).collect().await;
+
+        assert_eq!(topics.len(), 1);
+        by_id.assert();
+        paged.assert();
+        assert!(!format!("{:?}", client).contains("testapikey"));
+    }
+
+    #[tokio::test]
+    async fn test_network_errors_do_not_leak_identity() {
+        let config = OARSConfig::new()
+            .basepath("http://127.0.0.1:1".to_string())
+            .email("test@example.com".to_string())
+            .apikey("testapikey".to_string())
+            .maxretries(0);
+        let client = OARSClient::new(config);
+        let error = client
+            .get_work_by_id(WorkId::new(2741809807))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, OARSError::NetworkError(_)));
+        let message = format!("{} {:?}", error, error);
+        assert!(!message.contains("testapikey"), "{}", message);
+        assert!(!message.contains("test@example.com"), "{}", message);
+        assert!(message.contains("redacted"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_stream_stops_at_query_limit() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/sources")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(cursor_body("testdata/source.json", Some("next")))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url()).dailylimit(1);
+        let client = OARSClient::new(config);
+        let mut sources = client.stream_sources(&Query::new());
+
+        assert!(sources.next().await.unwrap().is_ok());
+        assert!(matches!(
+            sources.next().await,
+            Some(Err(OARSError::QueryLimitReached))
+        ));
+        assert!(sources.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_works() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded(
+                    "search".into(),
+                    "\"open access\" AND unpaywall NOT preprint".into(),
+                ),
+                Matcher::UrlEncoded("filter".into(), "title.search:oa".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(list_body("testdata/work.json"))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let search = Search::phrase("open access")
+            .and(Search::terms("unpaywall"))
+            .and_not(Search::terms("preprint"));
+        let filter = Filter::new().search(SearchField::Title, &Search::terms("oa"));
+        let query = Query::new().search(search).filter(filter);
+        let works = client
+            .list_works(&query)
+            .await
+            .expect("Failed to search works");
+
+        assert_eq!(works.results.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_institutions_by_display_name() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/institutions")
+            .match_query(Matcher::UrlEncoded(
+                "filter".into(),
+                "display_name.search:indian institute of science".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(list_body("testdata/institution.json"))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let filter = Filter::new().search(
+            SearchField::DisplayName,
+            &Search::terms("indian institute of science"),
+        );
+        let institutions = client
+            .list_institutions(&filter.into())
+            .await
+            .expect("Failed to search institutions");
+
+        assert_eq!(institutions.results.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_works_with_query_parameters() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sort".into(), "cited_by_count:desc,publication_year".into()),
+                Matcher::UrlEncoded("per_page".into(), "50".into()),
+                Matcher::UrlEncoded("page".into(), "3".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(list_body("testdata/work.json"))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let query = Query::new()
+            .sort_desc("cited_by_count")
+            .sort("publication_year")
+            .per_page(50)
+            .page(3);
+        let works = client
+            .list_works(&query)
+            .await
+            .expect("Failed to list works");
+
+        assert_eq!(works.results.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_query_is_rejected_before_request() {
+        let config = OARSConfig::new().basepath("http://127.0.0.1:9".to_owned());
+        let client = OARSClient::new(config);
+        let result = client.list_funders(&Query::new().per_page(500)).await;
+
+        assert!(matches!(result, Err(OARSError::InvalidQuery(_))));
+        assert_eq!(client.get_querycount(), 0);
+    }
+
+    fn group_body(groups: &[(&str, usize)], next_cursor: Option<&str>) -> String {
+        let groups: Vec<String> = groups
+            .iter()
+            .map(|(key, count)| {
+                format!(
+                    r#"{{"key":"{}","key_display_name":"{}","count":{}}}"#,
+                    key, key, count
+                )
+            })
+            .collect();
+        let next_cursor = match next_cursor {
+            Some(cursor) => format!("\"{}\"", cursor),
+            None => "null".to_owned(),
+        };
+        format!(
+            r#"{{"meta":{{"count":3,"groups_count":{},"next_cursor":{}}},"results":[],"group_by":[{}]}}"#,
+            groups.len(),
+            next_cursor,
+            groups.join(",")
+        )
+    }
+
+    #[tokio::test]
+    async fn test_group_works() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("filter".into(), "publication_year:2020".into()),
+                Matcher::UrlEncoded("group_by".into(), "oa_status:include_unknown".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_body(
+                &[("gold", 12), ("closed", 7), ("unknown", 1)],
+                None,
+            ))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let query = Query::from(Filter::new().equals("publication_year", 2020));
+        let groups = client
+            .group_works(&GroupBy::new("oa_status").include_unknown(), &query)
+            .await
+            .expect("Failed to group works");
+
+        assert_eq!(groups.meta.groups_count, Some(3));
+        assert_eq!(groups.group_by[0].key.as_deref(), Some("gold"));
+        assert_eq!(groups.group_by[0].count, Some(12));
+    }
+
+    #[tokio::test]
+    async fn test_group_works_rejects_select_and_sample() {
+        let mut server = mockito::Server::new_async().await;
+        let m = server.mock("GET", "/works").expect(0).create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let group_by = GroupBy::new("oa_status");
+        for query in [Query::new().select(["id"]), Query::new().sample(10)] {
+            let result = client.group_works(&group_by, &query).await;
+            assert!(matches!(result, Err(OARSError::InvalidQuery(_))));
+        }
+
+        assert_eq!(client.get_querycount(), 0);
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_stream_work_groups_follows_cursor() {
+        let mut server = mockito::Server::new_async().await;
+        let _first = server
+            .mock("GET", "/works")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("group_by".into(), "authorships.institutions.id".into()),
+                Matcher::UrlEncoded("cursor".into(), "*".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_body(&[("I1", 5), ("I2", 3)], Some("page2")))
+            .create();
+        let _second = server
+            .mock("GET", "/works")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "page2".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(group_body(&[("I3", 1)], None))
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let groups: Vec<Group> = client
+            .stream_work_groups(&GroupBy::new("authorships.institutions.id"), &Query::new())
+            .map(|group| group.expect("Failed to stream groups"))
+            .collect()
+            .await;
+
+        let keys: Vec<_> = groups.iter().map(|g| g.key.as_deref().unwrap()).collect();
+        assert_eq!(keys, vec!["I1", "I2", "I3"]);
+        assert_eq!(client.get_querycount(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_works_with_select() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::UrlEncoded(
+                "select".into(),
+                "id,doi,title,cited_by_count".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"meta":{"count":1},"results":[{"id":"https://openalex.org/W2741809807",
+                "doi":"https://doi.org/10.7717/peerj.4375","title":"The state of OA",
+                "cited_by_count":817}]}"#,
+            )
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let query = Query::new().select(["id", "doi", "title", "cited_by_count"]);
+        let works = client
+            .list_works(&query)
+            .await
+            .expect("Failed to list works");
+
+        let projected = serde_json::to_value(&works.results[0]).unwrap();
+        let mut fields: Vec<&String> = projected.as_object().unwrap().keys().collect();
+        fields.sort();
+        assert_eq!(fields, vec!["cited_by_count", "doi", "id", "title"]);
+    }
+
+    #[tokio::test]
+    async fn test_select_typo_is_rejected_before_request() {
+        let config = OARSConfig::new().basepath("http://127.0.0.1:9".to_owned());
+        let client = OARSClient::new(config);
+        let query = Query::new().select(["id", "display_nmae"]);
+        let result = client.list_authors(&query).await;
+
+        assert!(matches!(result, Err(OARSError::InvalidQuery(_))));
+        assert_eq!(client.get_querycount(), 0);
+    }
+
+    fn stub_works_body(ids: &[&str]) -> String {
+        let results: Vec<String> = ids
+            .iter()
+            .map(|id| format!(r#"{{"id":"https://openalex.org/{}"}}"#, id))
+            .collect();
+        format!(
+            r#"{{"meta":{{"count":{}}},"results":[{}]}}"#,
+            ids.len(),
+            results.join(",")
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_works_by_ids_preserves_input_order() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("filter".into(), "openalex_id:W3|W1|W2|W4|W5".into()),
+                Matcher::UrlEncoded("per_page".into(), "50".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(stub_works_body(&["W1", "W9", "W3", "W4"]))
+            .expect(1)
+            .create();
+        let _gone = server
+            .mock("GET", "/works/W2")
+            .with_status(404)
+            .expect(1)
+            .create();
+        let _merged = server
+            .mock("GET", "/works/W5")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"https://openalex.org/W9"}"#)
+            .expect(1)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let batch = client
+            .get_works_by_ids(&[
+                "W3".parse().unwrap(),
+                "https://openalex.org/W1".parse().unwrap(),
+                "W2".parse().unwrap(),
+                "w4".parse().unwrap(),
+                "W3".parse().unwrap(),
+                "W5".parse().unwrap(),
+            ])
+            .await
+            .expect("Failed to get works by IDs");
+
+        let positions: Vec<_> = batch
+            .results
+            .iter()
+            .map(|(id, work)| {
+                let found = work.as_ref().map(|w| w.openalex_id().unwrap().number());
+                (id.number(), found)
+            })
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                (3, Some(3)),
+                (1, Some(1)),
+                (2, None),
+                (4, Some(4)),
+                (3, Some(3)),
+                (5, Some(9))
+            ]
+        );
+        assert_eq!(batch.missing, vec![WorkId::new(2)]);
+        assert_eq!(
+            batch.merged,
+            HashMap::from([(WorkId::new(5), WorkId::new(9))])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_works_by_ids_resolves_merges_into_requested_records() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(stub_works_body(&["W1"]))
+            .expect(1)
+            .create();
+        let _merged = server
+            .mock("GET", "/works/W2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"https://openalex.org/W1"}"#)
+            .expect(1)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let batch = client
+            .get_works_by_ids(&[WorkId::new(1), WorkId::new(2)])
+            .await
+            .expect("Failed to get works by IDs");
+
+        assert!(batch.missing.is_empty());
+        assert_eq!(
+            batch.merged,
+            HashMap::from([(WorkId::new(2), WorkId::new(1))])
+        );
+        assert!(batch.results.iter().all(|(_, work)| work.is_some()));
+        assert_eq!(client.get_querycount(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_works_by_ids_resolves_every_id_merged_into_one_record() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(stub_works_body(&["W9"]))
+            .expect(1)
+            .create();
+        let _merged = server
+            .mock("GET", Matcher::Regex("^/works/W[23]$".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"https://openalex.org/W9"}"#)
+            .expect(2)
+            .create();
+        let _gone = server
+            .mock("GET", "/works/W4")
+            .with_status(404)
+            .expect(1)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let ids: Vec<WorkId> = (2..=4).map(WorkId::new).collect();
+        let batch = client
+            .get_works_by_ids(&ids)
+            .await
+            .expect("Failed to get works by IDs");
+
+        assert_eq!(batch.missing, vec![WorkId::new(4)]);
+        assert_eq!(
+            batch.merged,
+            HashMap::from([
+                (WorkId::new(2), WorkId::new(9)),
+                (WorkId::new(3), WorkId::new(9))
+            ])
+        );
+        assert_eq!(client.get_querycount(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_get_authors_by_ids_chunks_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let ids: Vec<AuthorId> = (0..120).map(AuthorId::new).collect();
+        let mocks: Vec<_> = ids
+            .chunks(MAX_BATCH_SIZE)
+            .map(|chunk| {
+                let filter: Vec<String> = chunk.iter().map(ToString::to_string).collect();
+                let results: Vec<String> = chunk
+                    .iter()
+                    .map(|id| format!(r#"{{"id":"https://openalex.org/{}"}}"#, id))
+                    .collect();
+                server
+                    .mock("GET", "/authors")
+                    .match_query(Matcher::UrlEncoded(
+                        "filter".into(),
+                        format!("openalex_id:{}", filter.join("|")),
+                    ))
+                    .with_status(200)
+                    .with_header("content-type", "application/json")
+                    .with_body(format!(
+                        r#"{{"meta":{{"count":{}}},"results":[{}]}}"#,
+                        chunk.len(),
+                        results.join(",")
+                    ))
+                    .expect(1)
+                    .create()
+            })
+            .collect();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let batch = client
+            .get_authors_by_ids(&ids)
+            .await
+            .expect("Failed to get authors by IDs");
+
+        assert_eq!(batch.results.len(), 120);
+        assert!(batch.results.iter().all(|(_, author)| author.is_some()));
+        assert!(batch.missing.is_empty());
+        assert!(batch.merged.is_empty());
+        assert_eq!(client.get_querycount(), 3);
+        mocks.iter().for_each(|m| m.assert());
+    }
+
+    #[tokio::test]
+    async fn test_get_work_by_doi() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works/doi:10.7717/peerj.4375")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let doi: ExternalId = "https://doi.org/10.7717/PEERJ.4375".parse().unwrap();
+        let work = client
+            .get_work_by_id(doi)
+            .await
+            .expect("Failed to get work by DOI");
+
+        crate::check_entity_against_json!(Work, "testdata/work.json", work);
+    }
+
+    #[tokio::test]
+    async fn test_get_work_by_doi_escapes_reserved_characters() {
+        let mut server = mockito::Server::new_async().await;
+        let m = server
+            .mock(
+                "GET",
+                "/works/doi:10.1002/(sici)1097-4636(199706)35:4%3C457::aid-jbm6%3E3.0.co;2-%23",
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let doi: ExternalId = "10.1002/(SICI)1097-4636(199706)35:4<457::AID-JBM6>3.0.CO;2-#"
+            .parse()
+            .unwrap();
+        client
+            .get_work_by_id(doi)
+            .await
+            .expect("Failed to get work by DOI");
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_get_author_by_orcid() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/authors/orcid:0000-0002-1825-0097")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/author.json")
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let orcid = ExternalId::orcid("https://orcid.org/0000-0002-1825-0097").unwrap();
+        let author = client
+            .get_author_by_id(orcid)
+            .await
+            .expect("Failed to get author by ORCID");
+
+        crate::check_entity_against_json!(Author, "testdata/author.json", author);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_namespace_is_rejected_before_request() {
+        let config = OARSConfig::new().basepath("http://127.0.0.1:9".to_owned());
+        let client = OARSClient::new(config);
+        let orcid = ExternalId::orcid("0000-0002-1825-0097").unwrap();
+        let result = client.get_work_by_id(orcid).await;
+
+        assert!(matches!(result, Err(OARSError::InvalidId(_))));
+        assert_eq!(client.get_querycount(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(503)
+            .expect(2)
+            .create();
+        let _ok = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url()).politeness(0.001);
+        let client = OARSClient::new(config);
+        client
+            .get_work_by_id(WorkId::new(2741809807))
+            .await
+            .expect("Failed to get work after retrying");
+
+        failing.assert();
+        assert_eq!(client.get_querycount(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_honors_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let _limited = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(429)
+            .with_header("retry-after", "1")
+            .expect(1)
+            .create();
+        let _ok = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url()).politeness(0.001);
+        let client = OARSClient::new(config);
+        let started = Instant::now();
+        client
+            .get_work_by_id(WorkId::new(2741809807))
+            .await
+            .expect("Failed to get work after rate limiting");
+
+        assert!(started.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiting_slows_every_clone() {
+        let mut server = mockito::Server::new_async().await;
+        let _limited = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create();
+        let _ok = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_file("testdata/work.json")
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url()).persecond(100.0);
+        let client = OARSClient::new(config);
+        let clone = client.clone();
+        client
+            .get_work_by_id(WorkId::new(2741809807))
+            .await
+            .expect("Failed to get work after rate limiting");
+
+        assert!(clone.ratelimiter.rate() < 100.0);
+        assert!(Arc::ptr_eq(&client.ratelimiter, &clone.ratelimiter));
+    }
+
+    #[tokio::test]
+    async fn test_retries_are_bounded_by_maxretries() {
+        let mut server = mockito::Server::new_async().await;
+        let m = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(500)
+            .expect(3)
+            .create();
+
+        let config = OARSConfig::new()
+            .basepath(server.url())
+            .maxretries(2)
+            .politeness(0.001);
+        let client = OARSClient::new(config);
+        let result = client.get_work_by_id(WorkId::new(2741809807)).await;
+
+        match result {
+            Err(error @ OARSError::Retried { attempts: 3, .. }) => {
+                assert!(matches!(
+                    error.root(),
+                    OARSError::ServerError { status: 500, .. }
+                ));
+            }
+            other => panic!("expected retries to be exhausted, got {:?}", other),
+        }
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_long_retry_after_is_returned_not_slept() {
+        let mut server = mockito::Server::new_async().await;
+        let m = server
+            .mock("GET", "/works/W2741809807")
+            .with_status(429)
+            .with_header("retry-after", "3600")
+            .expect(1)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let started = Instant::now();
+        let result = client.get_work_by_id(WorkId::new(2741809807)).await;
+
+        assert!(matches!(
+            result,
+            Err(OARSError::RateLimited {
+                retry_after: Some(delay)
+            }) if delay == Duration::from_secs(3600)
+        ));
+        assert!(started.elapsed() < MAX_BACKOFF);
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let m = server
+            .mock("GET", "/works/W1")
+            .with_status(404)
+            .expect(1)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url()).politeness(0.001);
+        let client = OARSClient::new(config);
+        let result = client.get_work_by_id(WorkId::new(1)).await;
+
+        assert!(matches!(result, Err(OARSError::NotFound(path)) if path == "works/W1"));
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_bad_request_carries_openalex_message() {
+        let mut server = mockito::Server::new_async().await;
+        let m = server
+            .mock("GET", "/works")
+            .match_query(Matcher::Any)
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"error":"Invalid query parameters error.","message":"publication_yer is not a valid field."}"#,
+            )
+            .expect(1)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let query = Query::new().filter(Filter::new().equals("publication_yer", 2020));
+        let result = client.list_works(&query).await;
+
+        match result {
+            Err(OARSError::BadRequest { error, message }) => {
+                assert_eq!(error, "Invalid query parameters error.");
+                assert_eq!(message, "publication_yer is not a valid field.");
+            }
+            other => panic!("expected a bad request, got {:?}", other),
+        }
+        m.assert();
+    }
+
+    #[tokio::test]
+    async fn test_auth_failures_are_not_bad_requests() {
+        let mut server = mockito::Server::new_async().await;
+        let _unauthorized = server
+            .mock("GET", "/works/W1")
+            .match_query(Matcher::Any)
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"error":"Unauthorized","message":"Invalid API key."}"#)
+            .create();
+        let _forbidden = server
+            .mock("GET", "/works/W2")
+            .match_query(Matcher::Any)
+            .with_status(403)
+            .with_body("Forbidden")
+            .create();
+
+        let config = OARSConfig::new()
+            .basepath(server.url())
+            .apikey("bad-key".to_owned());
+        let client = OARSClient::new(config);
+
+        match client.get_work_by_id(WorkId::new(1)).await {
+            Err(OARSError::Unauthorized(message)) => assert_eq!(message, "Invalid API key."),
+            other => panic!("expected unauthorized, got {:?}", other),
+        }
+        match client.get_work_by_id(WorkId::new(2)).await {
+            Err(OARSError::Forbidden(message)) => assert_eq!(message, "Forbidden"),
+            other => panic!("expected forbidden, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_deserialization_error_shows_snippet() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/works/W1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"id":"https://openalex.org/W1","cited_by_count":"many"}"#)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let result = client.get_work_by_id(WorkId::new(1)).await;
+
+        match result {
+            Err(OARSError::Deserialization { source, snippet }) => {
+                assert_eq!(source.path(), "cited_by_count");
+                assert!(
+                    snippet.contains(r#""cited_by_count":"many""#),
+                    "{}",
+                    snippet
+                );
+            }
+            other => panic!("expected a deserialization error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_backoff_delay_grows_and_is_capped() {
+        for attempt in 1..=4 {
+            let step = 0.5 * 2f64.powi(attempt as i32 - 1);
+            let delay = backoff_delay(0.5, attempt).as_secs_f64();
+            assert!(
+                delay >= step / 2.0 && delay <= step,
+                "{} -> {}",
+                attempt,
+                delay
+            );
+        }
+        assert!(backoff_delay(0.5, 40) <= MAX_BACKOFF);
+        assert_eq!(parse_retry_after(" 7 "), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+
+    #[tokio::test]
+    async fn test_audit_drift_reports_unmodelled_fields() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/topics")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"meta":{"count":2},"results":[
+                    {"id":"https://openalex.org/T1","works_count":"12","x_score":1.5},
+                    {"id":"https://openalex.org/T2","works_count":3}
+                ]}"#,
+            )
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let (topics, report) = client
+            .audit_drift::<Topic>(&Query::new())
+            .await
+            .expect("Failed to audit topics");
+
+        assert_eq!(topics.len(), 2);
+        assert_eq!(report.records, 2);
+        assert_eq!(report.unknown_fields.len(), 1);
+        assert_eq!(report.unknown_fields[0].path, "x_score");
+        assert_eq!(report.type_mismatches.len(), 1);
+        assert_eq!(report.type_mismatches[0].path, "works_count");
+        assert_eq!(report.type_mismatches[0].sample, "12");
+    }
+
+    #[tokio::test]
+    async fn test_audit_drift_pages_follows_cursor() {
+        let mut server = mockito::Server::new_async().await;
+        let first = server
+            .mock("GET", "/topics")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "*".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"meta":{"count":3,"next_cursor":"next"},"results":[
+                    {"id":"https://openalex.org/T1","works_count":"12"}
+                ]}"#,
+            )
+            .expect(1)
+            .create();
+        let second = server
+            .mock("GET", "/topics")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "next".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"meta":{"count":3,"next_cursor":"last"},"results":[
+                    {"id":"https://openalex.org/T2","works_count":"3","x_score":1.5}
+                ]}"#,
+            )
+            .expect(1)
+            .create();
+        let third = server
+            .mock("GET", "/topics")
+            .match_query(Matcher::UrlEncoded("cursor".into(), "last".into()))
+            .expect(0)
+            .create();
+
+        let config = OARSConfig::new().basepath(server.url());
+        let client = OARSClient::new(config);
+        let (topics, report) = client
+            .audit_drift_pages::<Topic>(&Query::new(), 2)
+            .await
+            .expect("Failed to audit topics");
+
+        assert_eq!(topics.len(), 2);
+        assert_eq!(report.records, 2);
+        assert_eq!(report.unknown_fields[0].path, "x_score");
+        assert_eq!(report.type_mismatches[0].path, "works_count");
+        assert_eq!(report.type_mismatches[0].count, 2);
+        first.assert();
+        second.assert();
+        third.assert();
+    }
 }
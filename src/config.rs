@@ -1,9 +1,13 @@
+use crate::quota::QuotaStore;
+use std::fmt;
+use std::sync::Arc;
 use tokio::time::Duration;
 
 static BASEPATH: &str = "https://api.openalex.org";
+pub(crate) static REDACTED: &str = "<redacted>";
 static USERAGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct OARSConfig {
     pub basepath: String,
     pub useragent: String,
@@ -14,6 +18,9 @@ pub struct OARSConfig {
     pub logornot: bool,
     pub dailylimit: usize,
     pub resetafter: Duration,
+    pub persecond: f64,
+    pub burstsize: usize,
+    pub quotastore: Option<Arc<dyn QuotaStore>>,
 }
 
 impl Default for OARSConfig {
@@ -28,15 +35,62 @@ impl Default for OARSConfig {
             logornot: false,
             dailylimit: 100_000,
             resetafter: Duration::from_secs(60 * 60 * 24),
+            persecond: 10.0,
+            burstsize: 10,
+            quotastore: None,
         }
     }
 }
 
+// Contact details identify the user to OpenAlex and the API key is a secret,
+// so neither is printed in full when a config (or a client holding one) is
+// logged.
+impl fmt::Debug for OARSConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let redacted = |value: &Option<String>| value.as_ref().map(|_| REDACTED);
+        f.debug_struct("OARSConfig")
+            .field("basepath", &self.basepath)
+            .field("useragent", &self.useragent)
+            .field("email", &redacted(&self.email))
+            .field("apikey", &redacted(&self.apikey))
+            .field("maxretries", &self.maxretries)
+            .field("politeness", &self.politeness)
+            .field("logornot", &self.logornot)
+            .field("dailylimit", &self.dailylimit)
+            .field("resetafter", &self.resetafter)
+            .field("persecond", &self.persecond)
+            .field("burstsize", &self.burstsize)
+            .field("quotastore", &self.quotastore)
+            .finish()
+    }
+}
+
 impl OARSConfig {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// The `User-Agent` sent with requests, with the contact email appended
+    /// as OpenAlex asks of polite-pool users.
+    pub(crate) fn user_agent(&self) -> String {
+        match &self.email {
+            Some(email) => format!("{} (mailto:{})", self.useragent, email),
+            None => self.useragent.clone(),
+        }
+    }
+
+    /// Query parameters identifying the caller, added to every request.
+    pub(crate) fn identity_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if let Some(email) = &self.email {
+            pairs.push(("mailto", email.clone()));
+        }
+        if let Some(key) = &self.apikey {
+            pairs.push(("api_key", key.clone()));
+        }
+        pairs
+    }
+
     pub fn basepath(mut self, basepath: String) -> Self {
         self.basepath = basepath;
         self
@@ -72,10 +126,29 @@ impl OARSConfig {
         self
     }
 
+    /// Length of a quota window. Windows are aligned to the Unix epoch, so the
+    /// default of one day resets at UTC midnight.
     pub fn resetafter(mut self, interval: Duration) -> Self {
         self.resetafter = interval;
         self
     }
+
+    /// Average number of requests sent per second; zero disables the limiter.
+    pub fn persecond(mut self, rate: f64) -> Self {
+        self.persecond = rate;
+        self
+    }
+
+    pub fn burstsize(mut self, size: usize) -> Self {
+        self.burstsize = size;
+        self
+    }
+
+    /// Where query counts are kept; each client counts in memory by default.
+    pub fn quotastore(mut self, store: impl QuotaStore + 'static) -> Self {
+        self.quotastore = Some(Arc::new(store));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -91,6 +164,9 @@ mod tests {
         assert_eq!(config.maxretries, 3);
         assert_eq!(config.politeness, 0.1);
         assert_eq!(config.resetafter, Duration::from_secs(60 * 60 * 24));
+        assert_eq!(config.persecond, 10.0);
+        assert_eq!(config.burstsize, 10);
+        assert!(config.quotastore.is_none());
         assert!(!config.logornot);
     }
 
@@ -103,7 +179,9 @@ mod tests {
             .maxretries(5)
             .politeness(0.2)
             .enable_logging()
-            .resetafter(Duration::from_secs(60 * 60 * 12)); // 12 hours
+            .resetafter(Duration::from_secs(60 * 60 * 12)) // 12 hours
+            .persecond(5.0)
+            .burstsize(2);
 
         assert_eq!(config.email, Some("test@example.com".to_string()));
         assert_eq!(config.apikey, Some("testapikey".to_string()));
@@ -111,5 +189,32 @@ mod tests {
         assert_eq!(config.politeness, 0.2);
         assert!(config.logornot);
         assert_eq!(config.resetafter, Duration::from_secs(60 * 60 * 12));
+        assert_eq!(config.persecond, 5.0);
+        assert_eq!(config.burstsize, 2);
+    }
+
+    #[test]
+    fn test_identity_is_sent_but_not_printed() {
+        let config = OARSConfig::new()
+            .email("test@example.com".to_string())
+            .apikey("testapikey".to_string());
+
+        assert_eq!(
+            config.user_agent(),
+            format!("{} (mailto:test@example.com)", USERAGENT)
+        );
+        assert_eq!(
+            config.identity_pairs(),
+            vec![
+                ("mailto", "test@example.com".to_string()),
+                ("api_key", "testapikey".to_string())
+            ]
+        );
+        let debug = format!("{:?}", config);
+        assert!(!debug.contains("test@example.com"), "{}", debug);
+        assert!(!debug.contains("testapikey"), "{}", debug);
+        assert!(debug.contains("email: Some(\"<redacted>\")"), "{}", debug);
+        assert_eq!(OARSConfig::new().user_agent(), USERAGENT);
+        assert!(OARSConfig::new().identity_pairs().is_empty());
     }
 }
@@ -0,0 +1,431 @@
+use crate::error::OARSError;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A calendar date in OpenAlex's `YYYY-MM-DD` format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u8, day: u8) -> Result<Self, OARSError> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(OARSError::InvalidDate(format!(
+                "{:04}-{:02}-{:02} is not a calendar date",
+                year, month, day
+            )));
+        }
+        Ok(Date { year, month, day })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = OARSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || OARSError::InvalidDate(format!("`{}` is not a YYYY-MM-DD date", s));
+        let mut parts = s.splitn(3, '-');
+        let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(invalid());
+        }
+        Date::new(
+            parse_digits(year).ok_or_else(invalid)? as i32,
+            parse_digits(month).ok_or_else(invalid)? as u8,
+            parse_digits(day).ok_or_else(invalid)? as u8,
+        )
+    }
+}
+
+/// A timestamp in OpenAlex's `YYYY-MM-DDTHH:MM:SS[.ffffff]` format, in UTC.
+///
+/// The number of fractional digits (and a trailing `Z`, if any) is kept, so a
+/// parsed value serializes back to exactly the text it was read from.
+/// Comparison only looks at the instant.
+#[derive(Clone, Copy, Debug)]
+pub struct DateTime {
+    date: Date,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    fraction_digits: u8,
+    zulu: bool,
+}
+
+impl DateTime {
+    pub fn new(date: Date, hour: u8, minute: u8, second: u8) -> Result<Self, OARSError> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(OARSError::InvalidDate(format!(
+                "{:02}:{:02}:{:02} is not a time of day",
+                hour, minute, second
+            )));
+        }
+        Ok(DateTime {
+            date,
+            hour,
+            minute,
+            second,
+            nanosecond: 0,
+            fraction_digits: 0,
+            zulu: false,
+        })
+    }
+
+    /// Sets the sub-second part, rendered with microsecond precision.
+    pub fn with_nanosecond(mut self, nanosecond: u32) -> Result<Self, OARSError> {
+        if nanosecond >= 1_000_000_000 {
+            return Err(OARSError::InvalidDate(format!(
+                "{} is out of range for nanoseconds",
+                nanosecond
+            )));
+        }
+        self.nanosecond = nanosecond;
+        self.fraction_digits = 6;
+        Ok(self)
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    fn key(&self) -> (Date, u8, u8, u8, u32) {
+        (
+            self.date,
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        )
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for DateTime {}
+
+impl Hash for DateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )?;
+        if self.fraction_digits > 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", &fraction[..self.fraction_digits as usize])?;
+        }
+        if self.zulu {
+            f.write_str("Z")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = OARSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || OARSError::InvalidDate(format!("`{}` is not a YYYY-MM-DDTHH:MM:SS timestamp", s));
+        let (date, time) = s.split_once('T').ok_or_else(invalid)?;
+        let (time, zulu) = match time.strip_suffix('Z') {
+            Some(time) => (time, true),
+            None => (time, false),
+        };
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time, None),
+        };
+
+        let fields: Vec<&str> = time.split(':').collect();
+        if fields.len() != 3 || fields.iter().any(|field| field.len() != 2) {
+            return Err(invalid());
+        }
+        let field = |i: usize| parse_digits(fields[i]).map(|v| v as u8).ok_or_else(invalid);
+        let mut datetime = DateTime::new(date.parse()?, field(0)?, field(1)?, field(2)?)?;
+
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || fraction.len() > 9 {
+                return Err(invalid());
+            }
+            let digits = parse_digits(fraction).ok_or_else(invalid)?;
+            datetime.nanosecond = (digits * 10u64.pow(9 - fraction.len() as u32)) as u32;
+            datetime.fraction_digits = fraction.len() as u8;
+        }
+        datetime.zulu = zulu;
+        Ok(datetime)
+    }
+}
+
+macro_rules! string_serde {
+    ($name:ident) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+string_serde!(Date);
+string_serde!(DateTime);
+
+fn parse_digits(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_support {
+    use super::{Date, DateTime};
+    use crate::error::OARSError;
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+    impl From<Date> for NaiveDate {
+        fn from(date: Date) -> Self {
+            NaiveDate::from_ymd_opt(date.year, date.month.into(), date.day.into())
+                .expect("Date is always a valid calendar date")
+        }
+    }
+
+    impl TryFrom<NaiveDate> for Date {
+        type Error = OARSError;
+
+        fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+            Date::new(date.year(), date.month() as u8, date.day() as u8)
+        }
+    }
+
+    impl From<DateTime> for NaiveDateTime {
+        fn from(datetime: DateTime) -> Self {
+            NaiveDate::from(datetime.date)
+                .and_hms_nano_opt(
+                    datetime.hour.into(),
+                    datetime.minute.into(),
+                    datetime.second.into(),
+                    datetime.nanosecond,
+                )
+                .expect("DateTime is always a valid time of day")
+        }
+    }
+
+    impl TryFrom<NaiveDateTime> for DateTime {
+        type Error = OARSError;
+
+        fn try_from(datetime: NaiveDateTime) -> Result<Self, Self::Error> {
+            DateTime::new(
+                datetime.date().try_into()?,
+                datetime.hour() as u8,
+                datetime.minute() as u8,
+                datetime.second() as u8,
+            )?
+            .with_nanosecond(datetime.nanosecond())
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_support {
+    use super::{Date, DateTime};
+    use crate::error::OARSError;
+
+    impl TryFrom<Date> for time::Date {
+        type Error = OARSError;
+
+        fn try_from(date: Date) -> Result<Self, Self::Error> {
+            let month = time::Month::try_from(date.month)
+                .map_err(|err| OARSError::InvalidDate(err.to_string()))?;
+            time::Date::from_calendar_date(date.year, month, date.day)
+                .map_err(|err| OARSError::InvalidDate(err.to_string()))
+        }
+    }
+
+    impl TryFrom<time::Date> for Date {
+        type Error = OARSError;
+
+        fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+            Date::new(date.year(), date.month().into(), date.day())
+        }
+    }
+
+    impl TryFrom<DateTime> for time::PrimitiveDateTime {
+        type Error = OARSError;
+
+        fn try_from(datetime: DateTime) -> Result<Self, Self::Error> {
+            let time = time::Time::from_hms_nano(
+                datetime.hour,
+                datetime.minute,
+                datetime.second,
+                datetime.nanosecond,
+            )
+            .map_err(|err| OARSError::InvalidDate(err.to_string()))?;
+            Ok(time::PrimitiveDateTime::new(
+                datetime.date.try_into()?,
+                time,
+            ))
+        }
+    }
+
+    impl TryFrom<time::PrimitiveDateTime> for DateTime {
+        type Error = OARSError;
+
+        fn try_from(datetime: time::PrimitiveDateTime) -> Result<Self, Self::Error> {
+            DateTime::new(
+                datetime.date().try_into()?,
+                datetime.hour(),
+                datetime.minute(),
+                datetime.second(),
+            )?
+            .with_nanosecond(datetime.nanosecond())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_parsing_and_validation() {
+        let date: Date = "2018-02-13".parse().unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2018, 2, 13));
+        assert_eq!(date.to_string(), "2018-02-13");
+        assert!("2020-02-29".parse::<Date>().is_ok());
+        assert!("2019-02-29".parse::<Date>().is_err());
+        assert!("2019-13-01".parse::<Date>().is_err());
+        assert!("2019-1-01".parse::<Date>().is_err());
+        assert!("2019-01-01T00:00:00".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn test_datetime_round_trips_exactly() {
+        for text in [
+            "2024-04-03T10:42:06.109490",
+            "2024-04-03T10:42:06",
+            "2024-04-03T10:42:06.1",
+            "2024-04-03T10:42:06.109490Z",
+        ] {
+            let datetime: DateTime = text.parse().unwrap();
+            assert_eq!(datetime.to_string(), text);
+            let json = serde_json::to_string(&datetime).unwrap();
+            assert_eq!(json, format!("\"{}\"", text));
+        }
+        assert!("2024-04-03T24:00:00".parse::<DateTime>().is_err());
+        assert!("2024-04-03 10:42:06".parse::<DateTime>().is_err());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let earlier: DateTime = "2024-04-03T10:42:06.1".parse().unwrap();
+        let later: DateTime = "2024-04-03T10:42:06.109490".parse().unwrap();
+        assert!(earlier < later);
+        assert_eq!(
+            "2024-04-03T10:42:06.100".parse::<DateTime>().unwrap(),
+            earlier
+        );
+        assert!("2018-02-13".parse::<Date>().unwrap() < "2018-10-01".parse::<Date>().unwrap());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversions() {
+        let datetime: DateTime = "2024-04-03T10:42:06.109490".parse().unwrap();
+        let naive = chrono::NaiveDateTime::from(datetime);
+        assert_eq!(naive.to_string(), "2024-04-03 10:42:06.109490");
+        assert_eq!(DateTime::try_from(naive).unwrap(), datetime);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_conversions() {
+        let datetime: DateTime = "2024-04-03T10:42:06.109490".parse().unwrap();
+        let primitive = time::PrimitiveDateTime::try_from(datetime).unwrap();
+        assert_eq!(primitive.microsecond(), 109_490);
+        assert_eq!(DateTime::try_from(primitive).unwrap(), datetime);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::bakery::{DecodeError, Leavenable, PathSegment};
+use crate::entities::common::Unmodelled;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// One kind of schema drift, aggregated over every record of an audit.
+///
+/// Paths use `[]` for array positions, e.g. `authorships[].institutions[].lineage`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Drift {
+    pub path: String,
+    pub count: usize,
+    pub sample: Value,
+    pub detail: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DriftReport {
+    pub records: usize,
+    pub unknown_fields: Vec<Drift>,
+    pub type_mismatches: Vec<Drift>,
+}
+
+impl DriftReport {
+    pub fn is_empty(&self) -> bool {
+        self.unknown_fields.is_empty() && self.type_mismatches.is_empty()
+    }
+}
+
+/// Decodes records leniently while collecting schema drift.
+///
+/// A field whose type does not match the model is recorded and dropped (set
+/// to `null`, or removed when `null` is not accepted either) and decoding is
+/// retried, so one bad field does not hide the others.
+#[derive(Debug, Default)]
+pub struct DriftAudit {
+    records: usize,
+    unknown_fields: BTreeMap<String, Drift>,
+    type_mismatches: BTreeMap<String, Drift>,
+}
+
+impl DriftAudit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn leaven<L, I>(&mut self, input: I) -> Result<L, DecodeError>
+    where
+        L: DeserializeOwned + Unmodelled,
+        I: Leavenable,
+    {
+        let mut value: Value = input.leaven()?;
+        self.records += 1;
+        let record = self.decode_lenient::<L>(&mut value)?;
+        record.visit_unmodelled(&mut String::new(), &mut |path, value| {
+            self.unknown_fields
+                .entry(path.to_owned())
+                .and_modify(|drift| drift.count += 1)
+                .or_insert(Drift {
+                    path: path.to_owned(),
+                    count: 1,
+                    sample: value.clone(),
+                    detail: None,
+                });
+        });
+        Ok(record)
+    }
+
+    pub fn report(&self) -> DriftReport {
+        DriftReport {
+            records: self.records,
+            unknown_fields: self.unknown_fields.values().cloned().collect(),
+            type_mismatches: self.type_mismatches.values().cloned().collect(),
+        }
+    }
+
+    fn decode_lenient<L: DeserializeOwned>(&mut self, value: &mut Value) -> Result<L, DecodeError> {
+        let mut nulled: Vec<Vec<PathSegment>> = Vec::new();
+        loop {
+            let err = match (&*value).leaven::<L>() {
+                Ok(record) => return Ok(record),
+                Err(err) => err,
+            };
+            let segments = err.segments().to_vec();
+            let first_attempt = !nulled.contains(&segments);
+            let sample = match pointer_mut(value, &segments) {
+                Some(slot) if !segments.is_empty() => slot.clone(),
+                _ => {
+                    self.record_mismatch(&segments, Value::Null, &err);
+                    return Err(err);
+                }
+            };
+            if first_attempt {
+                self.record_mismatch(&segments, sample, &err);
+                if let Some(slot) = pointer_mut(value, &segments) {
+                    *slot = Value::Null;
+                }
+                nulled.push(segments);
+            } else if remove(value, &segments) {
+                // Removing an array element shifts the next one into this
+                // path, and that one deserves its own attempt.
+                nulled.retain(|path| *path != segments);
+            } else {
+                return Err(err);
+            }
+        }
+    }
+
+    fn record_mismatch(&mut self, segments: &[PathSegment], sample: Value, err: &DecodeError) {
+        let path = normalized_path(segments);
+        self.type_mismatches
+            .entry(path.clone())
+            .and_modify(|drift| drift.count += 1)
+            .or_insert(Drift {
+                path,
+                count: 1,
+                sample,
+                detail: Some(err.inner().to_string()),
+            });
+    }
+}
+
+fn normalized_path(segments: &[PathSegment]) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        match segment {
+            PathSegment::Key(key) => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            PathSegment::Index(_) => path.push_str("[]"),
+            PathSegment::Opaque => path.push('?'),
+        }
+    }
+    if path.is_empty() {
+        path.push('.');
+    }
+    path
+}
+
+fn pointer_mut<'a>(value: &'a mut Value, segments: &[PathSegment]) -> Option<&'a mut Value> {
+    segments
+        .iter()
+        .try_fold(value, |value, segment| match (segment, value) {
+            (PathSegment::Key(key), Value::Object(map)) => map.get_mut(key),
+            (PathSegment::Index(index), Value::Array(items)) => items.get_mut(*index),
+            _ => None,
+        })
+}
+
+fn remove(value: &mut Value, segments: &[PathSegment]) -> bool {
+    let Some((last, parent)) = segments.split_last() else {
+        return false;
+    };
+    match (last, pointer_mut(value, parent)) {
+        (PathSegment::Key(key), Some(Value::Object(map))) => map.remove(key).is_some(),
+        (PathSegment::Index(index), Some(Value::Array(items))) if *index < items.len() => {
+            items.remove(*index);
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::work::Work;
+    use serde_json::json;
+
+    fn work_json() -> Value {
+        let json = std::fs::read_to_string("testdata/work.json").unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_clean_records_report_no_drift() {
+        let mut audit = DriftAudit::new();
+        let _: Work = audit.leaven(&work_json()).unwrap();
+        let report = audit.report();
+        assert_eq!(report.records, 1);
+        assert!(report.is_empty(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_unknown_fields_are_reported_with_paths() {
+        let mut audit = DriftAudit::new();
+        for _ in 0..2 {
+            let mut value = work_json();
+            value["x_new_metric"] = json!(0.5);
+            value["authorships"][0]["institutions"][0]["x_rank"] = json!(7);
+            value["authorships"][1]["institutions"][0]["x_rank"] = json!(8);
+            let _: Work = audit.leaven(&value).unwrap();
+        }
+
+        let report = audit.report();
+        assert!(report.type_mismatches.is_empty());
+        assert_eq!(
+            report.unknown_fields,
+            vec![
+                Drift {
+                    path: "authorships[].institutions[].x_rank".to_owned(),
+                    count: 4,
+                    sample: json!(7),
+                    detail: None,
+                },
+                Drift {
+                    path: "x_new_metric".to_owned(),
+                    count: 2,
+                    sample: json!(0.5),
+                    detail: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_keys_are_reported_verbatim() {
+        let key = "\u{0}unmodelled\u{0}x_score";
+        let mut value = work_json();
+        value["primary_location"]["source"][key] = json!(1);
+
+        let mut audit = DriftAudit::new();
+        let work: Work = audit.leaven(&value).unwrap();
+        let report = audit.report();
+
+        assert_eq!(
+            report.unknown_fields[0].path,
+            format!("primary_location.source.{}", key)
+        );
+        let serialized = serde_json::to_value(&work).unwrap();
+        assert_eq!(serialized["primary_location"]["source"][key], json!(1));
+    }
+
+    #[test]
+    fn test_type_mismatches_are_all_collected() {
+        let mut value = work_json();
+        value["cited_by_count"] = json!("many");
+        value["authorships"][0]["institutions"][0]["lineage"] = json!("I1");
+        value["referenced_works"][0] = json!(42);
+
+        let mut audit = DriftAudit::new();
+        let work: Work = audit.leaven(&value).unwrap();
+        let report = audit.report();
+
+        let paths: Vec<&str> = report
+            .type_mismatches
+            .iter()
+            .map(|drift| drift.path.as_str())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "authorships[].institutions[].lineage",
+                "cited_by_count",
+                "referenced_works[]"
+            ]
+        );
+        assert_eq!(report.type_mismatches[1].sample, json!("many"));
+        assert!(report.type_mismatches[1]
+            .detail
+            .as_deref()
+            .unwrap()
+            .contains("expected"));
+        assert!(work.extra().is_empty());
+    }
+
+    #[test]
+    fn test_consecutive_bad_array_elements_are_all_counted() {
+        let mut value = work_json();
+        value["referenced_works"][0] = json!(42);
+        value["referenced_works"][1] = json!(43);
+        let total = value["referenced_works"].as_array().unwrap().len();
+
+        let mut audit = DriftAudit::new();
+        let work: Work = audit.leaven(&value).unwrap();
+        let report = audit.report();
+
+        assert_eq!(report.type_mismatches.len(), 1);
+        assert_eq!(report.type_mismatches[0].path, "referenced_works[]");
+        assert_eq!(report.type_mismatches[0].count, 2);
+        assert_eq!(work.referenced_works().len(), total - 2);
+    }
+}
@@ -1,44 +1,66 @@
-use crate::bakery::{Deflatable, Deflated, Deflation, Leavenable};
+use crate::bakery::{DecodeError, Deflatable, Deflated, Deflation, Leavenable};
+use crate::dates::{Date, DateTime};
 use crate::entities::common::{
-    Affiliation, AuthorIds, CountByYear, DehydratedConcept, DehydratedInstitution, SummaryStats,
+    nullable, Affiliation, AuthorIds, Count, CountsByYear, DehydratedConcept,
+    DehydratedInstitution, Extra, SummaryStats,
 };
+use crate::ids::AuthorId;
+use crate::traits::Entity;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Error as SerdeError};
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Author {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    affiliations: Option<Vec<Affiliation>>,
+    affiliations: Option<Option<Vec<Affiliation>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_count: Option<i32>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    counts_by_year: Option<Vec<CountByYear>>,
+    counts_by_year: Option<Option<Vec<CountsByYear>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    created_date: Option<String>,
+    created_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name_alternatives: Option<Vec<String>>,
+    display_name_alternatives: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    id: Option<Option<AuthorId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ids: Option<AuthorIds>,
+    ids: Option<Option<AuthorIds>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    last_known_institution: Option<DehydratedInstitution>,
+    last_known_institution: Option<Option<DehydratedInstitution>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    last_known_institutions: Option<Vec<DehydratedInstitution>>,
+    last_known_institutions: Option<Option<Vec<DehydratedInstitution>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    orcid: Option<String>,
+    orcid: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    summary_stats: Option<SummaryStats>,
+    summary_stats: Option<Option<SummaryStats>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_date: Option<String>,
+    updated_date: Option<Option<DateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_api_url: Option<String>,
+    works_api_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    x_concepts: Option<Vec<DehydratedConcept>>,
+    x_concepts: Option<Option<Vec<DehydratedConcept>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Author {
@@ -46,11 +68,63 @@ impl Author {
         Self::default()
     }
 
-    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, SerdeError> {
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, DecodeError> {
         L::leaven(input)
     }
 }
 
+entity_accessors!(Author, AuthorBuilder {
+    affiliations: slice<Affiliation>,
+    cited_by_count: copy<Count>,
+    counts_by_year: slice<CountsByYear>,
+    created_date: copy<Date>,
+    display_name: str,
+    display_name_alternatives: slice<String>,
+    id: id<AuthorId>,
+    ids: ref<AuthorIds>,
+    last_known_institution: ref<DehydratedInstitution>,
+    last_known_institutions: slice<DehydratedInstitution>,
+    orcid: orcid,
+    summary_stats: ref<SummaryStats>,
+    updated_date: copy<DateTime>,
+    works_api_url: str,
+    works_count: copy<Count>,
+    x_concepts: slice<DehydratedConcept>,
+});
+
+impl Entity for Author {
+    const ENDPOINT: &'static str = "authors";
+    const FIELDS: &'static [&'static str] = &[
+        "affiliations",
+        "cited_by_count",
+        "counts_by_year",
+        "created_date",
+        "display_name",
+        "display_name_alternatives",
+        "id",
+        "ids",
+        "last_known_institution",
+        "last_known_institutions",
+        "orcid",
+        "summary_stats",
+        "updated_date",
+        "works_api_url",
+        "works_count",
+        "x_concepts",
+    ];
+    const EXTERNAL_NAMESPACES: &'static [&'static str] = &["orcid", "mag"];
+
+    type Id = AuthorId;
+
+    fn openalex_id(&self) -> Option<&AuthorId> {
+        self.id.as_ref().and_then(Option::as_ref)
+    }
+}
+
 impl Deflatable for Author {
     fn deflate(&self, format: Deflation) -> Result<Deflated, SerdeError> {
         match format {
@@ -64,7 +138,10 @@ impl Deflatable for Author {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{entity_idempotence_desugarred, entity_idempotence_sugarred};
+    use crate::{
+        entity_fields_complete, entity_idempotence_desugarred, entity_idempotence_sugarred,
+        entity_round_trip_lossless,
+    };
 
     #[test]
     fn test_author_idempotence_sugarred() {
@@ -75,4 +152,33 @@ mod tests {
     fn test_author_idempotence_desugarred() {
         entity_idempotence_desugarred!(Author, "testdata/author.json");
     }
+
+    #[test]
+    fn test_author_fields_complete() {
+        entity_fields_complete!(Author, "testdata/author.json");
+    }
+
+    #[test]
+    fn test_author_accessors_complete() {
+        accessors_complete!(Author);
+    }
+
+    #[test]
+    fn test_author_summary_stats_are_counts() {
+        let author: Author = serde_json::from_value(serde_json::json!({
+            "works_count": 55,
+            "summary_stats": {"2yr_mean_citedness": 3.67, "h_index": 16, "i10_index": 17}
+        }))
+        .unwrap();
+        let stats = author.summary_stats().unwrap();
+        let h_index: Option<Count> = stats.h_index();
+        assert_eq!(h_index, Some(16));
+        assert_eq!(stats.i10_index(), Some(17));
+        assert!(stats.i10_index() <= author.works_count());
+    }
+
+    #[test]
+    fn test_author_round_trip_is_lossless() {
+        entity_round_trip_lossless!(Author, "testdata/author.json");
+    }
 }
@@ -1,761 +1,1572 @@
-use serde::{Deserialize, Serialize};
+use crate::dates::{Date, DateTime};
+use crate::entities::enums::{
+    AuthorPosition, CrossrefType, InstitutionType, OaStatus, RoleKind, SourceType, Version,
+    WorkType,
+};
+use crate::ids::{
+    AuthorId, ConceptId, FunderId, InstitutionId, OpenAlexId, PublisherId, SourceId, TopicId,
+    WorkId,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+/// Type of every work, citation and grant count. Unsigned and 64 bits wide so
+/// totals summed across entities cannot overflow.
+pub type Count = u64;
+
+/// Fields OpenAlex returned that this crate does not model yet. They are kept
+/// so that deflating a leavened record does not lose data.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Extra(Map<String, Value>);
+
+impl Extra {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn into_inner(self) -> Map<String, Value> {
+        self.0
+    }
+}
+
+impl Deref for Extra {
+    type Target = Map<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Extra {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Types that keep the fields they do not model in [`Extra`] maps, at any
+/// depth, so that a drift audit can find them.
+pub trait Unmodelled {
+    /// Calls `visit` with the path and value of every unmodelled field, paths
+    /// written as in [`Drift`](crate::drift::Drift) and starting from `path`.
+    fn visit_unmodelled(&self, path: &mut String, visit: &mut dyn FnMut(&str, &Value));
+}
+
+impl<T: Unmodelled> Unmodelled for Option<T> {
+    fn visit_unmodelled(&self, path: &mut String, visit: &mut dyn FnMut(&str, &Value)) {
+        if let Some(value) = self {
+            value.visit_unmodelled(path, visit);
+        }
+    }
+}
+
+impl<T: Unmodelled> Unmodelled for Vec<T> {
+    fn visit_unmodelled(&self, path: &mut String, visit: &mut dyn FnMut(&str, &Value)) {
+        let len = path.len();
+        path.push_str("[]");
+        for item in self {
+            item.visit_unmodelled(path, visit);
+        }
+        path.truncate(len);
+    }
+}
+
+// Field types with no `Extra` of their own.
+macro_rules! fully_modelled {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Unmodelled for $ty {
+            fn visit_unmodelled(&self, _: &mut String, _: &mut dyn FnMut(&str, &Value)) {}
+        })*
+    };
+}
+
+fully_modelled!(
+    String,
+    bool,
+    i32,
+    i64,
+    u32,
+    u64,
+    usize,
+    f64,
+    HashMap<String, String>,
+    HashMap<String, Vec<usize>>,
+    Date,
+    DateTime,
+    OpenAlexId,
+    AuthorId,
+    ConceptId,
+    FunderId,
+    InstitutionId,
+    PublisherId,
+    SourceId,
+    TopicId,
+    WorkId,
+    AuthorPosition,
+    CrossrefType,
+    InstitutionType,
+    OaStatus,
+    RoleKind,
+    SourceType,
+    Version,
+    WorkType,
+);
+
+/// Appends `key` to a dotted field path, returning the length to truncate
+/// back to.
+pub(crate) fn push_key(path: &mut String, key: &str) -> usize {
+    let len = path.len();
+    if !path.is_empty() {
+        path.push('.');
+    }
+    path.push_str(key);
+    len
+}
+
+/// Deserializes a modelled field so that an explicit `null` (`Some(None)`) is
+/// kept apart from an absent key (`None`) and written back on deflate.
+pub(crate) fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Authorship {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub author: Option<Author>,
+    author: Option<Option<Author>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub author_position: Option<String>,
+    author_position: Option<Option<AuthorPosition>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub countries: Option<Vec<String>>,
+    countries: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub institutions: Option<Vec<Institution>>,
+    institutions: Option<Option<Vec<Institution>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_corresponding: Option<bool>,
+    is_corresponding: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub raw_affiliation_string: Option<String>,
+    raw_affiliation_string: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub raw_affiliation_strings: Option<Vec<String>>,
+    raw_affiliation_strings: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub raw_author_name: Option<String>,
+    raw_author_name: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Authorship {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Authorship, AuthorshipBuilder {
+    author: ref<Author>,
+    author_position: ref<AuthorPosition>,
+    countries: slice<String>,
+    institutions: slice<Institution>,
+    is_corresponding: copy<bool>,
+    raw_affiliation_string: str,
+    raw_affiliation_strings: slice<String>,
+    raw_author_name: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Author {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<AuthorId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orcid: Option<String>,
+    orcid: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Author {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Author, AuthorBuilder {
+    id: id<AuthorId>,
+    display_name: str,
+    orcid: orcid,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Institution {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<InstitutionId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ror: Option<String>,
+    ror: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    country_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lineage: Option<Vec<String>>,
+    lineage: Option<Option<Vec<InstitutionId>>>,
     #[serde(rename = "type")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    type_: Option<Option<InstitutionType>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Institution {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Institution, InstitutionBuilder {
+    id: id<InstitutionId>,
+    display_name: str,
+    ror: ror,
+    country_code: str,
+    lineage: id_list<InstitutionId>,
+    type_ as "type": ref<InstitutionType>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Apc {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value: Option<usize>,
+    value: Option<Option<u64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    currency: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub provenance: Option<String>,
+    provenance: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub value_usd: Option<usize>,
+    value_usd: Option<Option<u64>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Apc {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Apc, ApcBuilder {
+    value: copy<u64>,
+    currency: str,
+    provenance: str,
+    value_usd: copy<u64>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Location {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_accepted: Option<bool>,
+    is_accepted: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_oa: Option<bool>,
+    is_oa: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_published: Option<bool>,
+    is_published: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub landing_page_url: Option<String>,
+    landing_page_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub license: Option<String>,
+    license: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pdf_url: Option<String>,
+    pdf_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<Source>,
+    source: Option<Option<Source>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub version: Option<String>,
+    version: Option<Option<Version>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Location {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Location, LocationBuilder {
+    is_accepted: copy<bool>,
+    is_oa: copy<bool>,
+    is_published: copy<bool>,
+    landing_page_url: str,
+    license: str,
+    pdf_url: str,
+    source: ref<Source>,
+    version: ref<Version>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Source {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_organization: Option<String>,
+    host_organization: Option<Option<OpenAlexId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_organization_lineage: Option<Vec<String>>,
+    host_organization_lineage: Option<Option<Vec<OpenAlexId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_organization_lineage_names: Option<Vec<String>>,
+    host_organization_lineage_names: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_organization_name: Option<String>,
+    host_organization_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<SourceId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_in_doaj: Option<bool>,
+    is_in_doaj: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_oa: Option<bool>,
+    is_oa: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issn: Option<Vec<String>>,
+    issn: Option<Option<Vec<String>>>,
     #[serde(rename = "issn_l")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issn_l: Option<String>,
+    issn_l: Option<Option<String>>,
     #[serde(rename = "type")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub type_: Option<String>,
+    type_: Option<Option<SourceType>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Source {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Source, SourceBuilder {
+    display_name: str,
+    host_organization: id<OpenAlexId>,
+    host_organization_lineage: id_list<OpenAlexId>,
+    host_organization_lineage_names: slice<String>,
+    host_organization_name: str,
+    id: id<SourceId>,
+    is_in_doaj: copy<bool>,
+    is_oa: copy<bool>,
+    issn: slice<String>,
+    issn_l: str,
+    type_ as "type": ref<SourceType>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Biblio {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub volume: Option<String>,
+    volume: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issue: Option<String>,
+    issue: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub first_page: Option<String>,
+    first_page: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub last_page: Option<String>,
+    last_page: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Biblio {
     pub fn new() -> Self {
         Self::default()
     }
-}
 
-#[derive(Serialize, Deserialize, Debug, Default)]
-pub struct YearCount {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub year: Option<usize>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cited_by_count: Option<usize>,
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-impl YearCount {
-    pub fn new() -> Self {
-        Self::default()
+accessors!(
+    Biblio,
+    BiblioBuilder {
+        volume: str,
+        issue: str,
+        first_page: str,
+        last_page: str,
     }
-}
+);
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct PercentileYear {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub max: Option<usize>,
+    max: Option<Option<usize>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub min: Option<usize>,
+    min: Option<Option<usize>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl PercentileYear {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(PercentileYear, PercentileYearBuilder {
+    max: copy<usize>,
+    min: copy<usize>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Concept {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<ConceptId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikidata: Option<String>,
+    wikidata: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub level: Option<usize>,
+    level: Option<Option<u32>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score: Option<f64>,
+    score: Option<Option<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Concept {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Concept, ConceptBuilder {
+    id: id<ConceptId>,
+    wikidata: str,
+    display_name: str,
+    level: copy<u32>,
+    score: copy<f64>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Grant {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub funder: Option<String>,
+    funder: Option<Option<FunderId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub funder_display_name: Option<String>,
+    funder_display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub award_id: Option<String>,
+    award_id: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Grant {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Grant, GrantBuilder {
+    funder: id<FunderId>,
+    funder_display_name: str,
+    award_id: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Keyword {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub keyword: Option<String>,
+    keyword: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score: Option<f64>,
+    score: Option<Option<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Keyword {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Keyword, KeywordBuilder {
+    keyword: str,
+    score: copy<f64>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct MeshTag {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub descriptor_ui: Option<String>,
+    descriptor_ui: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub descriptor_name: Option<String>,
+    descriptor_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub qualifier_ui: Option<String>,
+    qualifier_ui: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub qualifier_name: Option<String>,
+    qualifier_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_major_topic: Option<bool>,
+    is_major_topic: Option<Option<bool>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl MeshTag {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(MeshTag, MeshTagBuilder {
+    descriptor_ui: str,
+    descriptor_name: str,
+    qualifier_ui: str,
+    qualifier_name: str,
+    is_major_topic: copy<bool>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct WorkIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<WorkId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub doi: Option<String>,
+    doi: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mag: Option<String>,
+    mag: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pmid: Option<String>,
+    pmid: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub pmcid: Option<String>,
+    pmcid: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl WorkIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(WorkIds, WorkIdsBuilder {
+    openalex: id<WorkId>,
+    doi: doi,
+    mag: str,
+    pmid: str,
+    pmcid: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct OpenAccess {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub is_oa: Option<bool>,
+    is_oa: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub oa_status: Option<String>,
+    oa_status: Option<Option<OaStatus>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub oa_url: Option<String>,
+    oa_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub any_repository_has_fulltext: Option<bool>,
+    any_repository_has_fulltext: Option<Option<bool>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl OpenAccess {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(OpenAccess, OpenAccessBuilder {
+    is_oa: copy<bool>,
+    oa_status: ref<OaStatus>,
+    oa_url: str,
+    any_repository_has_fulltext: copy<bool>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Sdg {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score: Option<f64>,
+    score: Option<Option<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Sdg {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Sdg, SdgBuilder {
+    id: str,
+    display_name: str,
+    score: copy<f64>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Topic {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub domain: Option<Domain>,
+    domain: Option<Option<Domain>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub field: Option<Field>,
+    field: Option<Option<Field>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<TopicId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score: Option<f64>,
+    score: Option<Option<f64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subfield: Option<Subfield>,
+    subfield: Option<Option<Subfield>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Topic {
     pub fn new() -> Self {
         Self::default()
     }
-}
-
-#[derive(Serialize, Deserialize, Debug, Default)]
-pub struct CountByYear {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub year: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub works_count: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub cited_by_count: Option<i32>,
-}
 
-impl CountByYear {
-    pub fn new() -> Self {
-        Self::default()
+    pub fn extra(&self) -> &Extra {
+        &self.extra
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Topic, TopicBuilder {
+    display_name: str,
+    domain: ref<Domain>,
+    field: ref<Field>,
+    id: id<TopicId>,
+    score: copy<f64>,
+    subfield: ref<Subfield>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct PublisherIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<PublisherId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ror: Option<String>,
+    ror: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikidata: Option<String>,
+    wikidata: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl PublisherIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(PublisherIds, PublisherIdsBuilder {
+    openalex: id<PublisherId>,
+    ror: ror,
+    wikidata: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Role {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub role: Option<String>,
+    role: Option<Option<RoleKind>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<OpenAlexId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Role {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Role, RoleBuilder {
+    role: ref<RoleKind>,
+    id: id<OpenAlexId>,
+    works_count: copy<Count>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct SummaryStats {
     #[serde(rename = "2yr_mean_citedness")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub two_year_mean_citedness: Option<f64>,
+    two_year_mean_citedness: Option<Option<f64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub h_index: Option<i32>,
+    h_index: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub i10_index: Option<i32>,
+    i10_index: Option<Option<Count>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl SummaryStats {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(SummaryStats, SummaryStatsBuilder {
+    two_year_mean_citedness as "2yr_mean_citedness": copy<f64>,
+    h_index: copy<Count>,
+    i10_index: copy<Count>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct CountsByYear {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub year: Option<i32>,
+    year: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub cited_by_count: Option<i32>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl CountsByYear {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(CountsByYear, CountsByYearBuilder {
+    year: copy<i32>,
+    works_count: copy<Count>,
+    cited_by_count: copy<Count>,
+});
+
+#[deprecated(note = "use `CountsByYear`")]
+pub type YearCount = CountsByYear;
+#[deprecated(note = "use `CountsByYear`")]
+pub type CountByYear = CountsByYear;
+#[deprecated(note = "use `CountsByYearBuilder`")]
+pub type YearCountBuilder = CountsByYearBuilder;
+#[deprecated(note = "use `CountsByYearBuilder`")]
+pub type CountByYearBuilder = CountsByYearBuilder;
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct FunderIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub crossref: Option<String>,
+    crossref: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub doi: Option<String>,
+    doi: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<FunderId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ror: Option<String>,
+    ror: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikidata: Option<String>,
+    wikidata: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl FunderIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(FunderIds, FunderIdsBuilder {
+    crossref: str,
+    doi: doi,
+    openalex: id<FunderId>,
+    ror: ror,
+    wikidata: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Affiliation {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub institution: Option<DehydratedInstitution>,
+    institution: Option<Option<DehydratedInstitution>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub years: Option<Vec<i32>>,
+    years: Option<Option<Vec<i32>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Affiliation {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Affiliation, AffiliationBuilder {
+    institution: ref<DehydratedInstitution>,
+    years: slice<i32>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct AuthorIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<AuthorId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub orcid: Option<String>,
+    orcid: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scopus: Option<String>,
+    scopus: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub twitter: Option<String>,
+    twitter: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikipedia: Option<String>,
+    wikipedia: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl AuthorIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(AuthorIds, AuthorIdsBuilder {
+    openalex: id<AuthorId>,
+    orcid: orcid,
+    scopus: str,
+    twitter: str,
+    wikipedia: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct DehydratedInstitution {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<InstitutionId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ror: Option<String>,
+    ror: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    country_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
-    pub institution_type: Option<String>,
+    institution_type: Option<Option<InstitutionType>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub lineage: Option<Vec<String>>,
+    lineage: Option<Option<Vec<InstitutionId>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl DehydratedInstitution {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(DehydratedInstitution, DehydratedInstitutionBuilder {
+    id: id<InstitutionId>,
+    ror: ror,
+    display_name: str,
+    country_code: str,
+    institution_type as "type": ref<InstitutionType>,
+    lineage: id_list<InstitutionId>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct DehydratedConcept {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<ConceptId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikidata: Option<String>,
+    wikidata: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub level: Option<i32>,
+    level: Option<Option<u32>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub score: Option<f64>,
+    score: Option<Option<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl DehydratedConcept {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(DehydratedConcept, DehydratedConceptBuilder {
+    id: id<ConceptId>,
+    wikidata: str,
+    display_name: str,
+    level: copy<u32>,
+    score: copy<f64>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct ApcPrice {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub price: Option<i32>,
+    price: Option<Option<u64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<String>,
+    currency: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ApcPrice {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(ApcPrice, ApcPriceBuilder {
+    price: copy<u64>,
+    currency: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct SourceIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub fatcat: Option<String>,
+    fatcat: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issn: Option<Vec<String>>,
+    issn: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub issn_l: Option<String>,
+    issn_l: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mag: Option<String>,
+    mag: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<SourceId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikidata: Option<String>,
+    wikidata: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl SourceIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(SourceIds, SourceIdsBuilder {
+    fatcat: str,
+    issn: slice<String>,
+    issn_l: str,
+    mag: str,
+    openalex: id<SourceId>,
+    wikidata: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Society {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
+    url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub organization: Option<String>,
+    organization: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Society {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(
+    Society,
+    SocietyBuilder {
+        url: str,
+        organization: str,
+    }
+);
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct ConceptIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mag: Option<i64>,
+    mag: Option<Option<i64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<ConceptId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub umls_cui: Option<Vec<String>>,
+    umls_cui: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub umls_aui: Option<Vec<String>>,
+    umls_aui: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikidata: Option<String>,
+    wikidata: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikipedia: Option<String>,
+    wikipedia: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ConceptIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(ConceptIds, ConceptIdsBuilder {
+    mag: copy<i64>,
+    openalex: id<ConceptId>,
+    umls_cui: slice<String>,
+    umls_aui: slice<String>,
+    wikidata: str,
+    wikipedia: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct InternationalDisplayNames {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<HashMap<String, String>>,
+    display_name: Option<Option<HashMap<String, String>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl InternationalDisplayNames {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(InternationalDisplayNames, InternationalDisplayNamesBuilder {
+    display_name: ref<HashMap<String, String>>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct ConceptSummaryStats {
     #[serde(rename = "2yr_mean_citedness")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub two_year_mean_citedness: Option<f64>,
+    two_year_mean_citedness: Option<Option<f64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub h_index: Option<i32>,
+    h_index: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub i10_index: Option<i32>,
+    i10_index: Option<Option<Count>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl ConceptSummaryStats {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(ConceptSummaryStats, ConceptSummaryStatsBuilder {
+    two_year_mean_citedness as "2yr_mean_citedness": copy<f64>,
+    h_index: copy<Count>,
+    i10_index: copy<Count>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct DehydratedInstitutionWithRelationship {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<InstitutionId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ror: Option<String>,
+    ror: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    country_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
-    pub institution_type: Option<String>,
+    institution_type: Option<Option<InstitutionType>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub relationship: Option<String>,
+    relationship: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl DehydratedInstitutionWithRelationship {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(DehydratedInstitutionWithRelationship, DehydratedInstitutionWithRelationshipBuilder {
+    id: id<InstitutionId>,
+    ror: ror,
+    display_name: str,
+    country_code: str,
+    institution_type as "type": ref<InstitutionType>,
+    relationship: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Geo {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub city: Option<String>,
+    city: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub geonames_city_id: Option<String>,
+    geonames_city_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub region: Option<String>,
+    region: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country_code: Option<String>,
+    country_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub country: Option<String>,
+    country: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub latitude: Option<f64>,
+    latitude: Option<Option<f64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub longitude: Option<f64>,
+    longitude: Option<Option<f64>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Geo {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Geo, GeoBuilder {
+    city: str,
+    geonames_city_id: str,
+    region: str,
+    country_code: str,
+    country: str,
+    latitude: copy<f64>,
+    longitude: copy<f64>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct InstitutionIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub grid: Option<String>,
+    grid: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub mag: Option<String>,
+    mag: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<InstitutionId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub ror: Option<String>,
+    ror: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikipedia: Option<String>,
+    wikipedia: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikidata: Option<String>,
+    wikidata: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl InstitutionIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(InstitutionIds, InstitutionIdsBuilder {
+    grid: str,
+    mag: str,
+    openalex: id<InstitutionId>,
+    ror: ror,
+    wikipedia: str,
+    wikidata: str,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Repository {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<SourceId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_organization: Option<String>,
+    host_organization: Option<Option<OpenAlexId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_organization_name: Option<String>,
+    host_organization_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub host_organization_lineage: Option<Vec<String>>,
+    host_organization_lineage: Option<Option<Vec<OpenAlexId>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Repository {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(Repository, RepositoryBuilder {
+    id: id<SourceId>,
+    display_name: str,
+    host_organization: id<OpenAlexId>,
+    host_organization_name: str,
+    host_organization_lineage: id_list<OpenAlexId>,
+});
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Domain {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Domain {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(
+    Domain,
+    DomainBuilder {
+        id: str,
+        display_name: str,
+    }
+);
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Field {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Field {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(
+    Field,
+    FieldBuilder {
+        id: str,
+        display_name: str,
+    }
+);
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Subfield {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub id: Option<String>,
+    id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Subfield {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+accessors!(
+    Subfield,
+    SubfieldBuilder {
+        id: str,
+        display_name: str,
+    }
+);
+
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct TopicIds {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub openalex: Option<String>,
+    openalex: Option<Option<TopicId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub wikipedia: Option<String>,
+    wikipedia: Option<Option<String>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl TopicIds {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
 }
+
+accessors!(TopicIds, TopicIdsBuilder {
+    openalex: id<TopicId>,
+    wikipedia: str,
+});
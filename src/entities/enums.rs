@@ -0,0 +1,156 @@
+// OpenAlex lists "other" as a value of several of these fields; it is `OtherKind`,
+// since `Other` carries values this crate does not know about yet.
+
+use std::fmt;
+
+/// A value of a categorical field that its enum does not list.
+///
+/// It cannot be built directly: converting a string with `From` gives the
+/// matching variant when there is one, so equal wire values compare equal.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Unlisted(pub(crate) String);
+
+impl Unlisted {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Unlisted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+string_enum!(WorkType {
+    Article => "article",
+    BookChapter => "book-chapter",
+    Dataset => "dataset",
+    Preprint => "preprint",
+    Dissertation => "dissertation",
+    Book => "book",
+    Review => "review",
+    Paratext => "paratext",
+    Libguides => "libguides",
+    Letter => "letter",
+    ReferenceEntry => "reference-entry",
+    Report => "report",
+    Editorial => "editorial",
+    PeerReview => "peer-review",
+    Erratum => "erratum",
+    Standard => "standard",
+    Grant => "grant",
+    SupplementaryMaterials => "supplementary-materials",
+    Retraction => "retraction",
+    OtherKind => "other",
+});
+
+string_enum!(CrossrefType {
+    JournalArticle => "journal-article",
+    BookChapter => "book-chapter",
+    ProceedingsArticle => "proceedings-article",
+    PostedContent => "posted-content",
+    Dissertation => "dissertation",
+    Book => "book",
+    Monograph => "monograph",
+    EditedBook => "edited-book",
+    ReferenceEntry => "reference-entry",
+    ReferenceBook => "reference-book",
+    Report => "report",
+    ReportSeries => "report-series",
+    ReportComponent => "report-component",
+    Dataset => "dataset",
+    Database => "database",
+    Component => "component",
+    PeerReview => "peer-review",
+    Standard => "standard",
+    Grant => "grant",
+    Journal => "journal",
+    JournalIssue => "journal-issue",
+    JournalVolume => "journal-volume",
+    BookPart => "book-part",
+    BookSection => "book-section",
+    BookTrack => "book-track",
+    BookSet => "book-set",
+    BookSeries => "book-series",
+    Proceedings => "proceedings",
+    ProceedingsSeries => "proceedings-series",
+    OtherKind => "other",
+});
+
+string_enum!(OaStatus {
+    Diamond => "diamond",
+    Gold => "gold",
+    Green => "green",
+    Hybrid => "hybrid",
+    Bronze => "bronze",
+    Closed => "closed",
+});
+
+string_enum!(AuthorPosition {
+    First => "first",
+    Middle => "middle",
+    Last => "last",
+});
+
+string_enum!(InstitutionType {
+    Education => "education",
+    Healthcare => "healthcare",
+    Company => "company",
+    Archive => "archive",
+    Nonprofit => "nonprofit",
+    Government => "government",
+    Facility => "facility",
+    Funder => "funder",
+    OtherKind => "other",
+});
+
+string_enum!(SourceType {
+    Journal => "journal",
+    Repository => "repository",
+    Conference => "conference",
+    EbookPlatform => "ebook platform",
+    BookSeries => "book series",
+    Metadata => "metadata",
+    OtherKind => "other",
+});
+
+string_enum!(Version {
+    SubmittedVersion => "submittedVersion",
+    AcceptedVersion => "acceptedVersion",
+    PublishedVersion => "publishedVersion",
+});
+
+string_enum!(RoleKind {
+    Institution => "institution",
+    Funder => "funder",
+    Publisher => "publisher",
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_values_round_trip() {
+        let status: OaStatus = serde_json::from_str("\"hybrid\"").unwrap();
+        assert_eq!(status, OaStatus::Hybrid);
+        assert_eq!(serde_json::to_string(&status).unwrap(), "\"hybrid\"");
+        assert_eq!(
+            serde_json::from_str::<SourceType>("\"ebook platform\"").unwrap(),
+            SourceType::EbookPlatform
+        );
+        assert_eq!(Version::from("acceptedVersion"), Version::AcceptedVersion);
+        assert_eq!(WorkType::OtherKind.as_str(), "other");
+    }
+
+    #[test]
+    fn test_unknown_values_fall_back_to_other() {
+        let kind: InstitutionType = serde_json::from_str("\"observatory\"").unwrap();
+        assert!(matches!(&kind, InstitutionType::Other(other) if other.as_str() == "observatory"));
+        assert_eq!(kind, InstitutionType::from("observatory"));
+        assert_eq!(OaStatus::from("gold".to_owned()), OaStatus::Gold);
+        assert_eq!(serde_json::to_string(&kind).unwrap(), "\"observatory\"");
+        assert_eq!(kind.to_string(), "observatory");
+    }
+}
@@ -1,44 +1,68 @@
-use crate::bakery::{Deflatable, Deflated, Deflation, Leavenable};
-use crate::entities::common::{CountsByYear, FunderIds, Role, SummaryStats};
+use crate::bakery::{DecodeError, Deflatable, Deflated, Deflation, Leavenable};
+use crate::dates::{Date, DateTime};
+use crate::entities::common::{
+    nullable, Count, CountsByYear, Extra, FunderIds, Role, SummaryStats,
+};
+use crate::ids::FunderId;
+use crate::traits::Entity;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Error as SerdeError};
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Funder {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    alternate_titles: Option<Vec<String>>,
+    alternate_titles: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_count: Option<i64>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    country_code: Option<String>,
+    country_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    counts_by_year: Option<Vec<CountsByYear>>,
+    counts_by_year: Option<Option<Vec<CountsByYear>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    created_date: Option<String>,
+    created_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    grants_count: Option<i32>,
+    grants_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    homepage_url: Option<String>,
+    homepage_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    id: Option<Option<FunderId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ids: Option<FunderIds>,
+    ids: Option<Option<FunderIds>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_thumbnail_url: Option<String>,
+    image_thumbnail_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_url: Option<String>,
+    image_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    roles: Option<Vec<Role>>,
+    roles: Option<Option<Vec<Role>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    summary_stats: Option<SummaryStats>,
+    summary_stats: Option<Option<SummaryStats>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_date: Option<String>,
+    updated_date: Option<Option<DateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Funder {
@@ -46,11 +70,65 @@ impl Funder {
         Self::default()
     }
 
-    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, SerdeError> {
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, DecodeError> {
         L::leaven(input)
     }
 }
 
+entity_accessors!(Funder, FunderBuilder {
+    alternate_titles: slice<String>,
+    cited_by_count: copy<Count>,
+    country_code: str,
+    counts_by_year: slice<CountsByYear>,
+    created_date: copy<Date>,
+    description: str,
+    display_name: str,
+    grants_count: copy<Count>,
+    homepage_url: str,
+    id: id<FunderId>,
+    ids: ref<FunderIds>,
+    image_thumbnail_url: str,
+    image_url: str,
+    roles: slice<Role>,
+    summary_stats: ref<SummaryStats>,
+    updated_date: copy<DateTime>,
+    works_count: copy<Count>,
+});
+
+impl Entity for Funder {
+    const ENDPOINT: &'static str = "funders";
+    const FIELDS: &'static [&'static str] = &[
+        "alternate_titles",
+        "cited_by_count",
+        "country_code",
+        "counts_by_year",
+        "created_date",
+        "description",
+        "display_name",
+        "grants_count",
+        "homepage_url",
+        "id",
+        "ids",
+        "image_thumbnail_url",
+        "image_url",
+        "roles",
+        "summary_stats",
+        "updated_date",
+        "works_count",
+    ];
+    const EXTERNAL_NAMESPACES: &'static [&'static str] = &["ror", "wikidata"];
+
+    type Id = FunderId;
+
+    fn openalex_id(&self) -> Option<&FunderId> {
+        self.id.as_ref().and_then(Option::as_ref)
+    }
+}
+
 impl Deflatable for Funder {
     fn deflate(&self, format: Deflation) -> Result<Deflated, SerdeError> {
         match format {
@@ -64,7 +142,10 @@ impl Deflatable for Funder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{entity_idempotence_desugarred, entity_idempotence_sugarred};
+    use crate::{
+        entity_fields_complete, entity_idempotence_desugarred, entity_idempotence_sugarred,
+        entity_round_trip_lossless,
+    };
 
     #[test]
     fn test_funder_idempotence_sugarred() {
@@ -75,4 +156,19 @@ mod tests {
     fn test_funder_idempotence_desugarred() {
         entity_idempotence_desugarred!(Funder, "testdata/funder.json");
     }
+
+    #[test]
+    fn test_funder_fields_complete() {
+        entity_fields_complete!(Funder, "testdata/funder.json");
+    }
+
+    #[test]
+    fn test_funder_accessors_complete() {
+        accessors_complete!(Funder);
+    }
+
+    #[test]
+    fn test_funder_round_trip_is_lossless() {
+        entity_round_trip_lossless!(Funder, "testdata/funder.json");
+    }
 }
@@ -1,67 +1,99 @@
-use crate::bakery::{Deflatable, Deflated, Deflation, Leavenable};
+use crate::bakery::{DecodeError, Deflatable, Deflated, Deflation, Leavenable};
+use crate::dates::{Date, DateTime};
 use crate::entities::common::{
-    CountsByYear, DehydratedConcept, DehydratedInstitutionWithRelationship, Geo, InstitutionIds,
-    InternationalDisplayNames, Repository, Role, SummaryStats,
+    nullable, Count, CountsByYear, DehydratedConcept, DehydratedInstitutionWithRelationship, Extra,
+    Geo, InstitutionIds, InternationalDisplayNames, Repository, Role, SummaryStats,
 };
+use crate::entities::enums::InstitutionType;
+use crate::ids::InstitutionId;
+use crate::traits::Entity;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Error as SerdeError};
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Institution {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    associated_institutions: Option<Vec<DehydratedInstitutionWithRelationship>>,
+    associated_institutions: Option<Option<Vec<DehydratedInstitutionWithRelationship>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_count: Option<i64>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    country_code: Option<String>,
+    country_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    counts_by_year: Option<Vec<CountsByYear>>,
+    counts_by_year: Option<Option<Vec<CountsByYear>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    created_date: Option<String>,
+    created_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name_acronyms: Option<Vec<String>>,
+    display_name_acronyms: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name_alternatives: Option<Vec<String>>,
+    display_name_alternatives: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    geo: Option<Geo>,
+    geo: Option<Option<Geo>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    homepage_url: Option<String>,
+    homepage_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    id: Option<Option<InstitutionId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ids: Option<InstitutionIds>,
+    ids: Option<Option<InstitutionIds>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_thumbnail_url: Option<String>,
+    image_thumbnail_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_url: Option<String>,
+    image_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    international: Option<InternationalDisplayNames>,
+    international: Option<Option<InternationalDisplayNames>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    lineage: Option<Vec<String>>,
+    lineage: Option<Option<Vec<InstitutionId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    repositories: Option<Vec<Repository>>,
+    repositories: Option<Option<Vec<Repository>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    roles: Option<Vec<Role>>,
+    roles: Option<Option<Vec<Role>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ror: Option<String>,
+    ror: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    summary_stats: Option<SummaryStats>,
+    summary_stats: Option<Option<SummaryStats>>,
     #[serde(rename = "type")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    institution_type: Option<String>,
+    institution_type: Option<Option<InstitutionType>>,
     #[serde(rename = "type_id")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    institution_type_id: Option<String>,
+    institution_type_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_date: Option<String>,
+    updated_date: Option<Option<DateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_api_url: Option<String>,
+    works_api_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    x_concepts: Option<Vec<DehydratedConcept>>,
+    x_concepts: Option<Option<Vec<DehydratedConcept>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Institution {
@@ -69,11 +101,83 @@ impl Institution {
         Self::default()
     }
 
-    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, SerdeError> {
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, DecodeError> {
         L::leaven(input)
     }
 }
 
+entity_accessors!(Institution, InstitutionBuilder {
+    associated_institutions: slice<DehydratedInstitutionWithRelationship>,
+    cited_by_count: copy<Count>,
+    country_code: str,
+    counts_by_year: slice<CountsByYear>,
+    created_date: copy<Date>,
+    display_name: str,
+    display_name_acronyms: slice<String>,
+    display_name_alternatives: slice<String>,
+    geo: ref<Geo>,
+    homepage_url: str,
+    id: id<InstitutionId>,
+    ids: ref<InstitutionIds>,
+    image_thumbnail_url: str,
+    image_url: str,
+    international: ref<InternationalDisplayNames>,
+    lineage: id_list<InstitutionId>,
+    repositories: slice<Repository>,
+    roles: slice<Role>,
+    ror: ror,
+    summary_stats: ref<SummaryStats>,
+    institution_type as "type": ref<InstitutionType>,
+    institution_type_id as "type_id": str,
+    updated_date: copy<DateTime>,
+    works_api_url: str,
+    works_count: copy<Count>,
+    x_concepts: slice<DehydratedConcept>,
+});
+
+impl Entity for Institution {
+    const ENDPOINT: &'static str = "institutions";
+    const FIELDS: &'static [&'static str] = &[
+        "associated_institutions",
+        "cited_by_count",
+        "country_code",
+        "counts_by_year",
+        "created_date",
+        "display_name",
+        "display_name_acronyms",
+        "display_name_alternatives",
+        "geo",
+        "homepage_url",
+        "id",
+        "ids",
+        "image_thumbnail_url",
+        "image_url",
+        "international",
+        "lineage",
+        "repositories",
+        "roles",
+        "ror",
+        "summary_stats",
+        "type",
+        "type_id",
+        "updated_date",
+        "works_api_url",
+        "works_count",
+        "x_concepts",
+    ];
+    const EXTERNAL_NAMESPACES: &'static [&'static str] = &["ror", "mag", "wikidata"];
+
+    type Id = InstitutionId;
+
+    fn openalex_id(&self) -> Option<&InstitutionId> {
+        self.id.as_ref().and_then(Option::as_ref)
+    }
+}
+
 impl Deflatable for Institution {
     fn deflate(&self, format: Deflation) -> Result<Deflated, SerdeError> {
         match format {
@@ -97,4 +201,19 @@ mod tests {
     fn test_institution_idempotence_desugarred() {
         crate::entity_idempotence_desugarred!(Institution, "testdata/institution.json");
     }
+
+    #[test]
+    fn test_institution_fields_complete() {
+        crate::entity_fields_complete!(Institution, "testdata/institution.json");
+    }
+
+    #[test]
+    fn test_institution_accessors_complete() {
+        accessors_complete!(Institution);
+    }
+
+    #[test]
+    fn test_institution_round_trip_is_lossless() {
+        crate::entity_round_trip_lossless!(Institution, "testdata/institution.json");
+    }
 }
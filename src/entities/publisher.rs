@@ -1,48 +1,74 @@
-use crate::bakery::{Deflatable, Deflated, Deflation, Leavenable};
-use crate::entities::common::{CountByYear, PublisherIds, Role, SummaryStats};
+use crate::bakery::{DecodeError, Deflatable, Deflated, Deflation, Leavenable};
+use crate::dates::{Date, DateTime};
+use crate::entities::common::{
+    nullable, Count, CountsByYear, Extra, PublisherIds, Role, SummaryStats,
+};
+use crate::ids::PublisherId;
+use crate::traits::Entity;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Error as SerdeError};
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Publisher {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    alternate_titles: Option<Vec<String>>,
+    alternate_titles: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_count: Option<i64>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    country_codes: Option<Vec<String>>,
+    country_codes: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    counts_by_year: Option<Vec<CountByYear>>,
+    counts_by_year: Option<Option<Vec<CountsByYear>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    created_date: Option<String>,
+    created_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    hierarchy_level: Option<i32>,
+    hierarchy_level: Option<Option<u32>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
     homepage_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    id: Option<Option<PublisherId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ids: Option<PublisherIds>,
+    ids: Option<Option<PublisherIds>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_thumbnail_url: Option<String>,
+    image_thumbnail_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    image_url: Option<String>,
+    image_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    lineage: Option<Vec<String>>,
+    lineage: Option<Option<Vec<PublisherId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    parent_publisher: Option<Option<String>>,
+    parent_publisher: Option<Option<PublisherId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    roles: Option<Vec<Role>>,
+    roles: Option<Option<Vec<Role>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    sources_api_url: Option<String>,
+    sources_api_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    summary_stats: Option<SummaryStats>,
+    summary_stats: Option<Option<SummaryStats>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_date: Option<String>,
+    updated_date: Option<Option<DateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Publisher {
@@ -50,11 +76,69 @@ impl Publisher {
         Self::default()
     }
 
-    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, SerdeError> {
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, DecodeError> {
         L::leaven(input)
     }
 }
 
+entity_accessors!(Publisher, PublisherBuilder {
+    alternate_titles: slice<String>,
+    cited_by_count: copy<Count>,
+    country_codes: slice<String>,
+    counts_by_year: slice<CountsByYear>,
+    created_date: copy<Date>,
+    display_name: str,
+    hierarchy_level: copy<u32>,
+    homepage_url: str,
+    id: id<PublisherId>,
+    ids: ref<PublisherIds>,
+    image_thumbnail_url: str,
+    image_url: str,
+    lineage: id_list<PublisherId>,
+    parent_publisher: id<PublisherId>,
+    roles: slice<Role>,
+    sources_api_url: str,
+    summary_stats: ref<SummaryStats>,
+    updated_date: copy<DateTime>,
+    works_count: copy<Count>,
+});
+
+impl Entity for Publisher {
+    const ENDPOINT: &'static str = "publishers";
+    const FIELDS: &'static [&'static str] = &[
+        "alternate_titles",
+        "cited_by_count",
+        "country_codes",
+        "counts_by_year",
+        "created_date",
+        "display_name",
+        "hierarchy_level",
+        "homepage_url",
+        "id",
+        "ids",
+        "image_thumbnail_url",
+        "image_url",
+        "lineage",
+        "parent_publisher",
+        "roles",
+        "sources_api_url",
+        "summary_stats",
+        "updated_date",
+        "works_count",
+    ];
+    const EXTERNAL_NAMESPACES: &'static [&'static str] = &["ror", "wikidata"];
+
+    type Id = PublisherId;
+
+    fn openalex_id(&self) -> Option<&PublisherId> {
+        self.id.as_ref().and_then(Option::as_ref)
+    }
+}
+
 impl Deflatable for Publisher {
     fn deflate(&self, format: Deflation) -> Result<Deflated, SerdeError> {
         match format {
@@ -77,4 +161,53 @@ mod tests {
     fn test_publisher_idempotence_desugarred() {
         crate::entity_idempotence_desugarred!(Publisher, "testdata/publisher.json");
     }
+
+    #[test]
+    fn test_publisher_fields_complete() {
+        crate::entity_fields_complete!(Publisher, "testdata/publisher.json");
+    }
+
+    #[test]
+    fn test_publisher_accessors_complete() {
+        accessors_complete!(Publisher);
+    }
+
+    #[test]
+    fn test_publisher_round_trip_is_lossless() {
+        crate::entity_round_trip_lossless!(Publisher, "testdata/publisher.json");
+    }
+
+    #[test]
+    fn test_publisher_accessors() {
+        let publisher =
+            Publisher::leaven(std::path::PathBuf::from("testdata/publisher.json")).unwrap();
+        assert_eq!(publisher.display_name(), Some("Springer Nature"));
+        assert_eq!(
+            publisher.homepage_url(),
+            Some("https://www.springernature.com")
+        );
+        assert_eq!(publisher.parent_publisher(), None);
+        assert_eq!(publisher.lineage(), &[PublisherId::new(4310319965)]);
+    }
+
+    #[test]
+    fn test_publisher_keeps_null_apart_from_absent() {
+        let null = Publisher::leaven(r#"{"parent_publisher":null}"#).unwrap();
+        let absent = Publisher::leaven("{}").unwrap();
+        assert_eq!(null.parent_publisher(), None);
+        assert_eq!(absent.parent_publisher(), None);
+
+        let deflate = |publisher: &Publisher| {
+            publisher
+                .deflate(Deflation::ToJsonValue)
+                .unwrap()
+                .to_json()
+                .unwrap()
+        };
+        assert_eq!(
+            deflate(&null),
+            serde_json::json!({"parent_publisher": null})
+        );
+        assert_eq!(deflate(&absent), serde_json::json!({}));
+    }
 }
@@ -1,65 +1,98 @@
-use crate::bakery::{Deflatable, Deflated, Deflation, Leavenable};
+use crate::bakery::{DecodeError, Deflatable, Deflated, Deflation, Leavenable};
+use crate::dates::{Date, DateTime};
 use crate::entities::common::{
-    ApcPrice, CountsByYear, DehydratedConcept, Society, SourceIds, SummaryStats,
+    nullable, ApcPrice, Count, CountsByYear, DehydratedConcept, Extra, Society, SourceIds,
+    SummaryStats,
 };
+use crate::entities::enums::SourceType;
+use crate::ids::{OpenAlexId, SourceId};
+use crate::traits::Entity;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Error as SerdeError};
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Source {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    abbreviated_title: Option<String>,
+    abbreviated_title: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    alternate_titles: Option<Vec<String>>,
+    alternate_titles: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    apc_prices: Option<Vec<ApcPrice>>,
+    apc_prices: Option<Option<Vec<ApcPrice>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    apc_usd: Option<i32>,
+    apc_usd: Option<Option<u64>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_count: Option<i64>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    country_code: Option<String>,
+    country_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    counts_by_year: Option<Vec<CountsByYear>>,
+    counts_by_year: Option<Option<Vec<CountsByYear>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    created_date: Option<String>,
+    created_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    homepage_url: Option<String>,
+    homepage_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    host_organization: Option<String>,
+    host_organization: Option<Option<OpenAlexId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    host_organization_lineage: Option<Vec<String>>,
+    host_organization_lineage: Option<Option<Vec<OpenAlexId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    host_organization_name: Option<String>,
+    host_organization_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    id: Option<Option<SourceId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ids: Option<SourceIds>,
+    ids: Option<Option<SourceIds>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    is_in_doaj: Option<bool>,
+    is_in_doaj: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    is_oa: Option<bool>,
+    is_oa: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    issn: Option<Vec<String>>,
+    issn: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    issn_l: Option<String>,
+    issn_l: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    societies: Option<Vec<Society>>,
+    societies: Option<Option<Vec<Society>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    summary_stats: Option<SummaryStats>,
+    summary_stats: Option<Option<SummaryStats>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "type")]
-    source_type: Option<String>,
+    source_type: Option<Option<SourceType>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_date: Option<String>,
+    updated_date: Option<Option<DateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_api_url: Option<String>,
+    works_api_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    x_concepts: Option<Vec<DehydratedConcept>>,
+    x_concepts: Option<Option<Vec<DehydratedConcept>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Source {
@@ -67,11 +100,83 @@ impl Source {
         Self::default()
     }
 
-    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, SerdeError> {
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, DecodeError> {
         L::leaven(input)
     }
 }
 
+entity_accessors!(Source, SourceBuilder {
+    abbreviated_title: str,
+    alternate_titles: slice<String>,
+    apc_prices: slice<ApcPrice>,
+    apc_usd: copy<u64>,
+    cited_by_count: copy<Count>,
+    country_code: str,
+    counts_by_year: slice<CountsByYear>,
+    created_date: copy<Date>,
+    display_name: str,
+    homepage_url: str,
+    host_organization: id<OpenAlexId>,
+    host_organization_lineage: id_list<OpenAlexId>,
+    host_organization_name: str,
+    id: id<SourceId>,
+    ids: ref<SourceIds>,
+    is_in_doaj: copy<bool>,
+    is_oa: copy<bool>,
+    issn: slice<String>,
+    issn_l: str,
+    societies: slice<Society>,
+    summary_stats: ref<SummaryStats>,
+    source_type as "type": ref<SourceType>,
+    updated_date: copy<DateTime>,
+    works_api_url: str,
+    works_count: copy<Count>,
+    x_concepts: slice<DehydratedConcept>,
+});
+
+impl Entity for Source {
+    const ENDPOINT: &'static str = "sources";
+    const FIELDS: &'static [&'static str] = &[
+        "abbreviated_title",
+        "alternate_titles",
+        "apc_prices",
+        "apc_usd",
+        "cited_by_count",
+        "country_code",
+        "counts_by_year",
+        "created_date",
+        "display_name",
+        "homepage_url",
+        "host_organization",
+        "host_organization_lineage",
+        "host_organization_name",
+        "id",
+        "ids",
+        "is_in_doaj",
+        "is_oa",
+        "issn",
+        "issn_l",
+        "societies",
+        "summary_stats",
+        "type",
+        "updated_date",
+        "works_api_url",
+        "works_count",
+        "x_concepts",
+    ];
+    const EXTERNAL_NAMESPACES: &'static [&'static str] = &["issn", "mag", "wikidata"];
+
+    type Id = SourceId;
+
+    fn openalex_id(&self) -> Option<&SourceId> {
+        self.id.as_ref().and_then(Option::as_ref)
+    }
+}
+
 impl Deflatable for Source {
     fn deflate(&self, format: Deflation) -> Result<Deflated, SerdeError> {
         match format {
@@ -85,7 +190,10 @@ impl Deflatable for Source {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{entity_idempotence_desugarred, entity_idempotence_sugarred};
+    use crate::{
+        entity_fields_complete, entity_idempotence_desugarred, entity_idempotence_sugarred,
+        entity_round_trip_lossless,
+    };
 
     #[test]
     fn test_source_idempotence_sugarred() {
@@ -96,4 +204,19 @@ mod tests {
     fn test_source_idempotence_desugarred() {
         entity_idempotence_desugarred!(Source, "testdata/source.json");
     }
+
+    #[test]
+    fn test_source_fields_complete() {
+        entity_fields_complete!(Source, "testdata/source.json");
+    }
+
+    #[test]
+    fn test_source_accessors_complete() {
+        accessors_complete!(Source);
+    }
+
+    #[test]
+    fn test_source_round_trip_is_lossless() {
+        entity_round_trip_lossless!(Source, "testdata/source.json");
+    }
 }
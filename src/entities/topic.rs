@@ -1,36 +1,54 @@
-use crate::bakery::{Deflatable, Deflated, Deflation, Leavenable};
-use crate::entities::common::{Domain, Field, Subfield, TopicIds};
+use crate::bakery::{DecodeError, Deflatable, Deflated, Deflation, Leavenable};
+use crate::dates::{Date, DateTime};
+use crate::entities::common::{nullable, Count, Domain, Extra, Field, Subfield, TopicIds};
+use crate::ids::TopicId;
+use crate::traits::Entity;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Error as SerdeError};
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Topic {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_count: Option<i64>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    created_date: Option<String>,
+    created_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    description: Option<String>,
+    description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    domain: Option<Domain>,
+    domain: Option<Option<Domain>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    field: Option<Field>,
+    field: Option<Option<Field>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    id: Option<Option<TopicId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ids: Option<TopicIds>,
+    ids: Option<Option<TopicIds>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    keywords: Option<Vec<String>>,
+    keywords: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    siblings: Option<Vec<String>>,
+    siblings: Option<Option<Vec<TopicId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    subfield: Option<Subfield>,
+    subfield: Option<Option<Subfield>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_date: Option<String>,
+    updated_date: Option<Option<DateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    works_count: Option<i32>,
+    works_count: Option<Option<Count>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Topic {
@@ -38,11 +56,57 @@ impl Topic {
         Self::default()
     }
 
-    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, SerdeError> {
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, DecodeError> {
         L::leaven(input)
     }
 }
 
+entity_accessors!(Topic, TopicBuilder {
+    cited_by_count: copy<Count>,
+    created_date: copy<Date>,
+    description: str,
+    display_name: str,
+    domain: ref<Domain>,
+    field: ref<Field>,
+    id: id<TopicId>,
+    ids: ref<TopicIds>,
+    keywords: slice<String>,
+    siblings: id_list<TopicId>,
+    subfield: ref<Subfield>,
+    updated_date: copy<DateTime>,
+    works_count: copy<Count>,
+});
+
+impl Entity for Topic {
+    const ENDPOINT: &'static str = "topics";
+    const FIELDS: &'static [&'static str] = &[
+        "cited_by_count",
+        "created_date",
+        "description",
+        "display_name",
+        "domain",
+        "field",
+        "id",
+        "ids",
+        "keywords",
+        "siblings",
+        "subfield",
+        "updated_date",
+        "works_count",
+    ];
+    const EXTERNAL_NAMESPACES: &'static [&'static str] = &[];
+
+    type Id = TopicId;
+
+    fn openalex_id(&self) -> Option<&TopicId> {
+        self.id.as_ref().and_then(Option::as_ref)
+    }
+}
+
 impl Deflatable for Topic {
     fn deflate(&self, format: Deflation) -> Result<Deflated, SerdeError> {
         match format {
@@ -66,4 +130,19 @@ mod tests {
     fn test_author_idempotence_desugarred() {
         crate::entity_idempotence_desugarred!(Topic, "testdata/topic.json");
     }
+
+    #[test]
+    fn test_topic_fields_complete() {
+        crate::entity_fields_complete!(Topic, "testdata/topic.json");
+    }
+
+    #[test]
+    fn test_topic_accessors_complete() {
+        accessors_complete!(Topic);
+    }
+
+    #[test]
+    fn test_topic_round_trip_is_lossless() {
+        crate::entity_round_trip_lossless!(Topic, "testdata/topic.json");
+    }
 }
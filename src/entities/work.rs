@@ -1,118 +1,301 @@
-use crate::bakery::{Deflatable, Deflated, Deflation, Leavenable};
+use crate::abstracts::{self, InvertedIndex};
+use crate::bakery::{DecodeError, Deflatable, Deflated, Deflation, Leavenable};
+use crate::dates::{Date, DateTime};
 use crate::entities::common::{
-    Apc, Authorship, Biblio, Concept, Grant, Keyword, Location, MeshTag, OpenAccess,
-    PercentileYear, Sdg, Topic, WorkIds, YearCount,
+    nullable, Apc, Authorship, Biblio, Concept, Count, CountsByYear, Extra, Grant, Keyword,
+    Location, MeshTag, OpenAccess, PercentileYear, Sdg, Topic, WorkIds,
 };
+use crate::entities::enums::{CrossrefType, WorkType};
+use crate::ids::{AuthorId, InstitutionId, WorkId};
+use crate::traits::Entity;
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Error as SerdeError};
-use std::collections::HashMap;
 
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Clone, Serialize, Deserialize, Debug, Default)]
 pub struct Work {
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    abstract_inverted_index: Option<HashMap<String, Vec<usize>>>,
+    abstract_inverted_index: Option<Option<InvertedIndex>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    authorships: Option<Vec<Authorship>>,
+    authorships: Option<Option<Vec<Authorship>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    apc_list: Option<Apc>,
+    apc_list: Option<Option<Apc>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    apc_paid: Option<Apc>,
+    apc_paid: Option<Option<Apc>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    best_oa_location: Option<Location>,
+    best_oa_location: Option<Option<Location>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    biblio: Option<Biblio>,
+    biblio: Option<Option<Biblio>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_api_url: Option<String>,
+    cited_by_api_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_count: Option<usize>,
+    cited_by_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    cited_by_percentile_year: Option<PercentileYear>,
+    cited_by_percentile_year: Option<Option<PercentileYear>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    concepts: Option<Vec<Concept>>,
+    concepts: Option<Option<Vec<Concept>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    corresponding_author_ids: Option<Vec<String>>,
+    corresponding_author_ids: Option<Option<Vec<AuthorId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    corresponding_institution_ids: Option<Vec<String>>,
+    corresponding_institution_ids: Option<Option<Vec<InstitutionId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    countries_distinct_count: Option<usize>,
+    countries_distinct_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    counts_by_year: Option<Vec<YearCount>>,
+    counts_by_year: Option<Option<Vec<CountsByYear>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    created_date: Option<String>,
+    created_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    display_name: Option<String>,
+    display_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    doi: Option<String>,
+    doi: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    fulltext_origin: Option<String>,
+    fulltext_origin: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    grants: Option<Vec<Grant>>,
+    grants: Option<Option<Vec<Grant>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    has_fulltext: Option<bool>,
+    has_fulltext: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<String>,
+    id: Option<Option<WorkId>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ids: Option<WorkIds>,
+    ids: Option<Option<WorkIds>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    indexed_in: Option<Vec<String>>,
+    indexed_in: Option<Option<Vec<String>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    institutions_distinct_count: Option<usize>,
+    institutions_distinct_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    is_paratext: Option<bool>,
+    is_paratext: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    is_retracted: Option<bool>,
+    is_retracted: Option<Option<bool>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    keywords: Option<Vec<Keyword>>,
+    keywords: Option<Option<Vec<Keyword>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    language: Option<String>,
+    language: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    locations: Option<Vec<Location>>,
+    locations: Option<Option<Vec<Location>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    locations_count: Option<usize>,
+    locations_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    mesh: Option<Vec<MeshTag>>,
+    mesh: Option<Option<Vec<MeshTag>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    ngrams_url: Option<String>,
+    ngrams_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    open_access: Option<OpenAccess>,
+    open_access: Option<Option<OpenAccess>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    primary_location: Option<Location>,
+    primary_location: Option<Option<Location>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    primary_topic: Option<Topic>,
+    primary_topic: Option<Option<Topic>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    publication_date: Option<String>,
+    publication_date: Option<Option<Date>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    publication_year: Option<usize>,
+    publication_year: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    referenced_works: Option<Vec<String>>,
+    referenced_works: Option<Option<Vec<WorkId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    referenced_works_count: Option<usize>,
+    referenced_works_count: Option<Option<Count>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    related_works: Option<Vec<String>>,
+    related_works: Option<Option<Vec<WorkId>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    sustainable_development_goals: Option<Vec<Sdg>>,
+    sustainable_development_goals: Option<Option<Vec<Sdg>>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    title: Option<String>,
+    title: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    topics: Option<Vec<Topic>>,
+    topics: Option<Option<Vec<Topic>>>,
     #[serde(rename = "type")]
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    work_type: Option<String>,
+    work_type: Option<Option<WorkType>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    type_crossref: Option<String>,
+    type_crossref: Option<Option<CrossrefType>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    updated_date: Option<String>,
+    updated_date: Option<Option<DateTime>>,
+    #[serde(default, deserialize_with = "nullable")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub versions: Option<Vec<String>>,
+    versions: Option<Option<Vec<String>>>,
+    #[serde(flatten)]
+    extra: Extra,
 }
 
 impl Work {
     pub fn new() -> Work {
         Self::default()
     }
-    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, SerdeError> {
+    pub fn leaven<L: Leavenable>(input: L) -> Result<Self, DecodeError> {
         L::leaven(input)
     }
+
+    pub fn extra(&self) -> &Extra {
+        &self.extra
+    }
+
+    /// The abstract as plaintext, rebuilt from `abstract_inverted_index`.
+    pub fn abstract_text(&self) -> Option<String> {
+        self.abstract_inverted_index
+            .as_ref()
+            .and_then(Option::as_ref)
+            .map(abstracts::reconstruct)
+    }
+}
+
+impl WorkBuilder {
+    /// Sets `abstract_inverted_index` to the inverted index of `text`.
+    pub fn abstract_text(mut self, text: &str) -> Self {
+        self.inner.abstract_inverted_index = Some(Some(abstracts::invert(text)));
+        self
+    }
+}
+
+entity_accessors!(Work, WorkBuilder {
+    abstract_inverted_index: ref<InvertedIndex>,
+    authorships: slice<Authorship>,
+    apc_list: ref<Apc>,
+    apc_paid: ref<Apc>,
+    best_oa_location: ref<Location>,
+    biblio: ref<Biblio>,
+    cited_by_api_url: str,
+    cited_by_count: copy<Count>,
+    cited_by_percentile_year: ref<PercentileYear>,
+    concepts: slice<Concept>,
+    corresponding_author_ids: id_list<AuthorId>,
+    corresponding_institution_ids: id_list<InstitutionId>,
+    countries_distinct_count: copy<Count>,
+    counts_by_year: slice<CountsByYear>,
+    created_date: copy<Date>,
+    display_name: str,
+    doi: doi,
+    fulltext_origin: str,
+    grants: slice<Grant>,
+    has_fulltext: copy<bool>,
+    id: id<WorkId>,
+    ids: ref<WorkIds>,
+    indexed_in: slice<String>,
+    institutions_distinct_count: copy<Count>,
+    is_paratext: copy<bool>,
+    is_retracted: copy<bool>,
+    keywords: slice<Keyword>,
+    language: str,
+    locations: slice<Location>,
+    locations_count: copy<Count>,
+    mesh: slice<MeshTag>,
+    ngrams_url: str,
+    open_access: ref<OpenAccess>,
+    primary_location: ref<Location>,
+    primary_topic: ref<Topic>,
+    publication_date: copy<Date>,
+    publication_year: copy<i32>,
+    referenced_works: id_list<WorkId>,
+    referenced_works_count: copy<Count>,
+    related_works: id_list<WorkId>,
+    sustainable_development_goals: slice<Sdg>,
+    title: str,
+    topics: slice<Topic>,
+    work_type as "type": ref<WorkType>,
+    type_crossref: ref<CrossrefType>,
+    updated_date: copy<DateTime>,
+    versions: slice<String>,
+});
+
+impl Entity for Work {
+    const ENDPOINT: &'static str = "works";
+    const FIELDS: &'static [&'static str] = &[
+        "abstract_inverted_index",
+        "apc_list",
+        "apc_paid",
+        "authorships",
+        "best_oa_location",
+        "biblio",
+        "cited_by_api_url",
+        "cited_by_count",
+        "cited_by_percentile_year",
+        "concepts",
+        "corresponding_author_ids",
+        "corresponding_institution_ids",
+        "countries_distinct_count",
+        "counts_by_year",
+        "created_date",
+        "display_name",
+        "doi",
+        "fulltext_origin",
+        "grants",
+        "has_fulltext",
+        "id",
+        "ids",
+        "indexed_in",
+        "institutions_distinct_count",
+        "is_paratext",
+        "is_retracted",
+        "keywords",
+        "language",
+        "locations",
+        "locations_count",
+        "mesh",
+        "ngrams_url",
+        "open_access",
+        "primary_location",
+        "primary_topic",
+        "publication_date",
+        "publication_year",
+        "referenced_works",
+        "referenced_works_count",
+        "related_works",
+        "sustainable_development_goals",
+        "title",
+        "topics",
+        "type",
+        "type_crossref",
+        "updated_date",
+        "versions",
+    ];
+    const EXTERNAL_NAMESPACES: &'static [&'static str] = &["doi", "mag", "pmid", "pmcid"];
+
+    type Id = WorkId;
+
+    fn openalex_id(&self) -> Option<&WorkId> {
+        self.id.as_ref().and_then(Option::as_ref)
+    }
 }
 
 impl Deflatable for Work {
@@ -128,6 +311,8 @@ impl Deflatable for Work {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::entities::enums::AuthorPosition;
+    use crate::error::OARSError;
 
     #[test]
     fn test_work_idempotence_sugarred() {
@@ -138,4 +323,165 @@ mod tests {
     fn test_work_idempotence_desugarred() {
         crate::entity_idempotence_desugarred!(Work, "testdata/work.json");
     }
+
+    #[test]
+    fn test_work_fields_complete() {
+        crate::entity_fields_complete!(Work, "testdata/work.json");
+    }
+
+    #[test]
+    fn test_work_accessors_complete() {
+        accessors_complete!(Work);
+    }
+
+    #[test]
+    fn test_work_round_trip_is_lossless() {
+        crate::entity_round_trip_lossless!(Work, "testdata/work.json");
+    }
+
+    #[test]
+    fn test_work_keeps_nested_unknown_fields() {
+        let json = std::fs::read_to_string("testdata/work.json").unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["authorships"][0]["institutions"][0]["x_unmodelled_field"] =
+            serde_json::json!(["kept"]);
+
+        let work: Work = (&value).leaven().unwrap();
+        let institution = &work.authorships()[0].institutions()[0];
+        assert_eq!(
+            institution.extra().get("x_unmodelled_field"),
+            Some(&serde_json::json!(["kept"]))
+        );
+
+        let deflated = work
+            .deflate(Deflation::ToJsonValue)
+            .unwrap()
+            .to_json()
+            .unwrap();
+        assert_eq!(
+            deflated["authorships"][0]["institutions"][0]["x_unmodelled_field"],
+            serde_json::json!(["kept"])
+        );
+    }
+
+    #[test]
+    fn test_work_accessors() {
+        let work = Work::leaven(std::path::PathBuf::from("testdata/work.json")).unwrap();
+        assert_eq!(
+            work.title(),
+            Some(
+                "The state of OA: a large-scale analysis of the prevalence and impact of Open \
+                 Access articles"
+            )
+        );
+        assert_eq!(work.doi(), Some("https://doi.org/10.7717/peerj.4375"));
+        assert_eq!(work.id(), Some(WorkId::new(2741809807)));
+        assert_eq!(work.work_type(), Some(&WorkType::Article));
+        assert_eq!(work.type_crossref(), Some(&CrossrefType::JournalArticle));
+        assert_eq!(work.authorships().len(), 9);
+        assert_eq!(work.publication_year(), Some(2018));
+        assert_eq!(
+            work.publication_date(),
+            Some(Date::new(2018, 2, 13).unwrap())
+        );
+        assert!(work.created_date() < work.updated_date().map(|updated| updated.date()));
+        let location = work.primary_location().unwrap();
+        assert_eq!(location.license(), Some("cc-by"));
+        assert_eq!(
+            location.source().and_then(|source| source.display_name()),
+            Some("PeerJ")
+        );
+        assert_eq!(
+            work.authorships()[0]
+                .author()
+                .and_then(|author| author.display_name()),
+            Some("Heather Piwowar")
+        );
+        assert!(Work::new().authorships().is_empty());
+        assert_eq!(Work::new().title(), None);
+    }
+
+    #[test]
+    fn test_work_counts_aggregate_without_casts() {
+        let json = std::fs::read_to_string("testdata/work.json").unwrap();
+        let work = Work::leaven(json.as_str()).unwrap();
+        let yearly: Count = work
+            .counts_by_year()
+            .iter()
+            .filter_map(|counts| counts.cited_by_count())
+            .sum();
+        assert!(yearly > 0);
+        assert_eq!(
+            work.referenced_works_count(),
+            Some(work.referenced_works().len() as Count)
+        );
+    }
+
+    #[test]
+    fn test_work_abstract_text_round_trips() {
+        let json = std::fs::read_to_string("testdata/work.json").unwrap();
+        let work = Work::leaven(json.as_str()).unwrap();
+        let text = work.abstract_text().unwrap();
+        assert!(text.starts_with("Despite growing interest in Open Access (OA) to scholarly"));
+        assert_eq!(text.split(' ').count(), 258);
+
+        let rebuilt = Work::builder().abstract_text(&text).build().unwrap();
+        assert_eq!(
+            rebuilt.abstract_inverted_index(),
+            work.abstract_inverted_index()
+        );
+        assert_eq!(Work::new().abstract_text(), None);
+    }
+
+    #[test]
+    fn test_work_builder() {
+        let author = crate::entities::common::Author::builder()
+            .id("https://openalex.org/A5023888391")
+            .display_name("Jason Priem")
+            .orcid("0000-0001-6187-6610")
+            .build()
+            .unwrap();
+        let authorship = Authorship::builder()
+            .author(author)
+            .author_position(AuthorPosition::First)
+            .build()
+            .unwrap();
+        let work = Work::builder()
+            .id("W2741809807")
+            .doi("10.7717/PEERJ.4375")
+            .title("The state of OA")
+            .publication_year(2018)
+            .authorships([authorship])
+            .referenced_works(["W1", "https://openalex.org/W2"])
+            .build()
+            .unwrap();
+
+        assert_eq!(work.id(), Some(WorkId::new(2741809807)));
+        assert_eq!(work.doi(), Some("https://doi.org/10.7717/peerj.4375"));
+        assert_eq!(work.referenced_works(), &[WorkId::new(1), WorkId::new(2)]);
+        assert_eq!(
+            serde_json::to_value(&work).unwrap()["authorships"][0]["author"],
+            serde_json::json!({
+                "id": "https://openalex.org/A5023888391",
+                "display_name": "Jason Priem",
+                "orcid": "https://orcid.org/0000-0001-6187-6610"
+            })
+        );
+    }
+
+    #[test]
+    fn test_work_builder_rejects_invalid_ids() {
+        assert!(matches!(
+            Work::builder().title("x").id("A5023888391").build(),
+            Err(OARSError::InvalidId(_))
+        ));
+        assert!(matches!(
+            Work::builder().related_works(["W1", "W2x"]).build(),
+            Err(OARSError::InvalidId(_))
+        ));
+        assert!(matches!(
+            Work::builder().doi("not a doi").build(),
+            Err(OARSError::InvalidId(_))
+        ));
+    }
 }
@@ -1,12 +1,155 @@
+use crate::bakery::DecodeError;
 use reqwest::Error as ReqwestError;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum OARSError {
     #[error("Network error occurred: {0}")]
     NetworkError(#[from] ReqwestError),
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Bad request: {error}: {message}")]
+    BadRequest { error: String, message: String },
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+    #[error("Rate limited by OpenAlex (retry after {retry_after:?})")]
+    RateLimited { retry_after: Option<Duration> },
+    #[error("Server error {status} (retry after {retry_after:?})")]
+    ServerError {
+        status: u16,
+        retry_after: Option<Duration>,
+    },
+    #[error("Failed to deserialize response: {source} near `{snippet}`")]
+    Deserialization {
+        #[source]
+        source: DecodeError,
+        snippet: String,
+    },
     #[error("Daily query limit reached")]
     QueryLimitReached,
+    #[error("Quota store error: {0}")]
+    QuotaStore(#[source] std::io::Error),
+    #[error("Failed to start the async runtime: {0}")]
+    Runtime(#[source] std::io::Error),
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+    #[error("Invalid identifier: {0}")]
+    InvalidId(String),
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+    #[error("Request failed after {attempts} attempts ({waited:?} spent waiting): {source}")]
+    Retried {
+        attempts: usize,
+        waited: Duration,
+        #[source]
+        source: Box<OARSError>,
+    },
     #[error("Unknown error occurred")]
     Unknown,
 }
+
+impl OARSError {
+    /// Whether repeating the same request could succeed. Of network errors,
+    /// only failures to connect, timeouts and broken response bodies qualify;
+    /// a request that could not be built fails the same way every time.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            OARSError::NetworkError(err) => err.is_connect() || err.is_timeout() || err.is_body(),
+            OARSError::RateLimited { .. } | OARSError::ServerError { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// The error that ended the request. Retried requests are reported as
+    /// [`OARSError::Retried`]; this looks through it to the last failure, so
+    /// callers can still tell a `NotFound` or `RateLimited` apart.
+    pub fn root(&self) -> &OARSError {
+        match self {
+            OARSError::Retried { source, .. } => source.root(),
+            error => error,
+        }
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self.root() {
+            OARSError::RateLimited { retry_after } | OARSError::ServerError { retry_after, .. } => {
+                *retry_after
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retryable_classification() {
+        assert!(OARSError::RateLimited { retry_after: None }.is_retryable());
+        assert!(OARSError::ServerError {
+            status: 502,
+            retry_after: None
+        }
+        .is_retryable());
+        assert!(!OARSError::NotFound("works/W1".to_owned()).is_retryable());
+        assert!(!OARSError::BadRequest {
+            error: "Invalid query parameters error.".to_owned(),
+            message: "unknown filter".to_owned()
+        }
+        .is_retryable());
+        assert!(!OARSError::Unauthorized("Invalid API key.".to_owned()).is_retryable());
+        assert!(!OARSError::Forbidden("Forbidden".to_owned()).is_retryable());
+        assert!(!OARSError::QueryLimitReached.is_retryable());
+        assert_eq!(
+            OARSError::RateLimited {
+                retry_after: Some(Duration::from_secs(3))
+            }
+            .retry_after(),
+            Some(Duration::from_secs(3))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_requests_are_not_retried() {
+        let built = reqwest::Client::new()
+            .get("http://[::1")
+            .build()
+            .unwrap_err();
+        assert!(!OARSError::NetworkError(built).is_retryable());
+
+        // A peer that does not speak HTTP fails the request after connecting.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let peer = std::thread::spawn(move || {
+            use std::io::{Read, Write};
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0; 1024]);
+            stream.write_all(b"not http\r\n\r\n").unwrap();
+        });
+        let sent = reqwest::get(format!("http://{}", addr)).await.unwrap_err();
+        peer.join().unwrap();
+        assert!(sent.is_request() && !sent.is_connect());
+        assert!(!OARSError::NetworkError(sent).is_retryable());
+    }
+
+    #[test]
+    fn test_root_looks_through_retries() {
+        let error = OARSError::Retried {
+            attempts: 4,
+            waited: Duration::from_secs(1),
+            source: Box::new(OARSError::RateLimited {
+                retry_after: Some(Duration::from_secs(3)),
+            }),
+        };
+        assert!(matches!(error.root(), OARSError::RateLimited { .. }));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(3)));
+        assert!(matches!(
+            OARSError::QueryLimitReached.root(),
+            OARSError::QueryLimitReached
+        ));
+    }
+}
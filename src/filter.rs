@@ -0,0 +1,177 @@
+use crate::error::OARSError;
+use crate::search::{Search, SearchField};
+use std::fmt;
+
+/// Builder for the OpenAlex `filter=` parameter.
+///
+/// Each call adds one `attribute:value` clause; clauses are joined with `,`,
+/// which OpenAlex treats as AND, so repeating an attribute narrows the result.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Filter {
+    clauses: Vec<Clause>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Clause {
+    attribute: String,
+    operator: &'static str,
+    // Alternatives, joined with `|` (OR) when rendered.
+    values: Vec<String>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn clause(mut self, attribute: &str, operator: &'static str, values: Vec<String>) -> Self {
+        self.clauses.push(Clause {
+            attribute: attribute.to_owned(),
+            operator,
+            values,
+        });
+        self
+    }
+
+    pub fn equals<V: ToString>(self, attribute: &str, value: V) -> Self {
+        self.clause(attribute, "", vec![value.to_string()])
+    }
+
+    pub fn not_equals<V: ToString>(self, attribute: &str, value: V) -> Self {
+        self.clause(attribute, "!", vec![value.to_string()])
+    }
+
+    pub fn any_of<I, V>(self, attribute: &str, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: ToString,
+    {
+        let values = values.into_iter().map(|v| v.to_string()).collect();
+        self.clause(attribute, "", values)
+    }
+
+    pub fn greater_than<V: ToString>(self, attribute: &str, value: V) -> Self {
+        self.clause(attribute, ">", vec![value.to_string()])
+    }
+
+    pub fn less_than<V: ToString>(self, attribute: &str, value: V) -> Self {
+        self.clause(attribute, "<", vec![value.to_string()])
+    }
+
+    /// Adds a `<field>.search` clause. Commas and `|` are removed from the
+    /// search text, since OpenAlex would read them as the end of the clause or
+    /// as an OR between values.
+    pub fn search(self, field: SearchField, search: &Search) -> Self {
+        let attribute = format!("{}.search", field.as_str());
+        let text = search.to_string().replace([',', '|'], "");
+        self.clause(&attribute, "", vec![text])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+
+    /// Rejects values containing `,` or `|`, which OpenAlex would read as the
+    /// start of another clause or of an OR alternative.
+    pub fn validate(&self) -> Result<(), OARSError> {
+        for clause in &self.clauses {
+            if let Some(value) = clause
+                .values
+                .iter()
+                .find(|value| value.contains([',', '|']))
+            {
+                return Err(OARSError::InvalidQuery(format!(
+                    "filter value `{}` for `{}` cannot contain `,` or `|`",
+                    value, clause.attribute
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, clause) in self.clauses.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(
+                f,
+                "{}:{}{}",
+                clause.attribute,
+                clause.operator,
+                clause.values.join("|")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_filter() {
+        let filter = Filter::new();
+        assert!(filter.is_empty());
+        assert_eq!(filter.to_string(), "");
+    }
+
+    #[test]
+    fn test_filter_operators() {
+        let filter = Filter::new()
+            .equals("publication_year", 2020)
+            .not_equals("type", "paratext")
+            .any_of("institutions.country_code", ["fr", "gb"])
+            .greater_than("cited_by_count", 100)
+            .less_than("authors_count", 5);
+        assert_eq!(
+            filter.to_string(),
+            "publication_year:2020,type:!paratext,institutions.country_code:fr|gb,\
+             cited_by_count:>100,authors_count:<5"
+        );
+    }
+
+    #[test]
+    fn test_filter_field_search() {
+        let filter = Filter::new()
+            .search(SearchField::Title, &Search::phrase("open access, citation"))
+            .search(SearchField::Abstract, &Search::terms("unpaywall"));
+        assert_eq!(
+            filter.to_string(),
+            "title.search:\"open access citation\",abstract.search:unpaywall"
+        );
+
+        let filter = Filter::new().search(SearchField::Title, &Search::terms("A|B testing"));
+        assert_eq!(filter.to_string(), "title.search:AB testing");
+        assert!(filter.validate().is_ok());
+    }
+
+    #[test]
+    fn test_filter_rejects_separators_in_values() {
+        assert!(Filter::new().equals("type", "article").validate().is_ok());
+        for filter in [
+            Filter::new().equals("display_name", "Smith, J."),
+            Filter::new().not_equals("type", "article|book"),
+            Filter::new().any_of("institutions.country_code", ["fr", "gb,us"]),
+        ] {
+            assert!(matches!(
+                filter.validate(),
+                Err(OARSError::InvalidQuery(msg)) if msg.contains("cannot contain")
+            ));
+        }
+    }
+
+    #[test]
+    fn test_filter_and_by_repetition() {
+        let filter = Filter::new()
+            .equals("institutions.country_code", "fr")
+            .equals("institutions.country_code", "gb");
+        assert_eq!(
+            filter.to_string(),
+            "institutions.country_code:fr,institutions.country_code:gb"
+        );
+    }
+}
@@ -0,0 +1,686 @@
+use crate::error::OARSError;
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// A non-OpenAlex identifier that OpenAlex can resolve to an entity.
+///
+/// Values are stored normalized: DOIs lowercased without a resolver prefix,
+/// ORCIDs and ISSNs hyphenated with an uppercase check character, RORs
+/// lowercased, PMCIDs and Wikidata QIDs with their uppercase letter prefix.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExternalId {
+    Doi(String),
+    Orcid(String),
+    Ror(String),
+    Pmid(String),
+    Pmcid(String),
+    Issn(String),
+    Mag(String),
+    Wikidata(String),
+}
+
+impl ExternalId {
+    pub fn doi(doi: &str) -> Result<Self, OARSError> {
+        let doi = strip_any_prefix(doi.trim(), &DOI_PREFIXES).to_lowercase();
+        match doi.split_once('/') {
+            Some((prefix, suffix)) if prefix.starts_with("10.") && !suffix.is_empty() => {
+                Ok(ExternalId::Doi(doi))
+            }
+            _ => Err(invalid("DOI", &doi)),
+        }
+    }
+
+    pub fn orcid(orcid: &str) -> Result<Self, OARSError> {
+        let orcid = strip_any_prefix(orcid.trim(), &ORCID_PREFIXES);
+        let chars: Vec<char> = orcid
+            .chars()
+            .filter(|c| *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let well_formed = chars.len() == 16
+            && chars[..15].iter().all(char::is_ascii_digit)
+            && (chars[15].is_ascii_digit() || chars[15] == 'X');
+        if !well_formed || orcid_check_char(&chars[..15]) != chars[15] {
+            return Err(invalid("ORCID", orcid));
+        }
+        let groups: Vec<String> = chars.chunks(4).map(|c| c.iter().collect()).collect();
+        Ok(ExternalId::Orcid(groups.join("-")))
+    }
+
+    pub fn ror(ror: &str) -> Result<Self, OARSError> {
+        let ror = strip_any_prefix(ror.trim(), &ROR_PREFIXES).to_lowercase();
+        if ror.len() == 9 && ror.starts_with('0') && ror.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            Ok(ExternalId::Ror(ror))
+        } else {
+            Err(invalid("ROR", &ror))
+        }
+    }
+
+    pub fn pmid(pmid: &str) -> Result<Self, OARSError> {
+        let pmid = strip_any_prefix(pmid.trim(), &PMID_PREFIXES).trim_end_matches('/');
+        if is_digits(pmid) {
+            Ok(ExternalId::Pmid(pmid.to_owned()))
+        } else {
+            Err(invalid("PMID", pmid))
+        }
+    }
+
+    pub fn pmcid(pmcid: &str) -> Result<Self, OARSError> {
+        let pmcid = strip_any_prefix(pmcid.trim(), &PMCID_PREFIXES)
+            .trim_end_matches('/')
+            .to_uppercase();
+        let digits = pmcid.strip_prefix("PMC").unwrap_or(&pmcid);
+        if is_digits(digits) {
+            Ok(ExternalId::Pmcid(format!("PMC{}", digits)))
+        } else {
+            Err(invalid("PMCID", &pmcid))
+        }
+    }
+
+    pub fn issn(issn: &str) -> Result<Self, OARSError> {
+        let issn = strip_any_prefix(issn.trim(), &ISSN_PREFIXES);
+        let chars: Vec<char> = issn
+            .chars()
+            .filter(|c| *c != '-')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        let well_formed = chars.len() == 8
+            && chars[..7].iter().all(char::is_ascii_digit)
+            && (chars[7].is_ascii_digit() || chars[7] == 'X');
+        if !well_formed || issn_check_char(&chars[..7]) != chars[7] {
+            return Err(invalid("ISSN", issn));
+        }
+        let issn: String = chars.iter().collect();
+        Ok(ExternalId::Issn(format!("{}-{}", &issn[..4], &issn[4..])))
+    }
+
+    pub fn mag(mag: &str) -> Result<Self, OARSError> {
+        let mag = mag.trim();
+        if is_digits(mag) {
+            Ok(ExternalId::Mag(mag.to_owned()))
+        } else {
+            Err(invalid("MAG ID", mag))
+        }
+    }
+
+    pub fn wikidata(wikidata: &str) -> Result<Self, OARSError> {
+        let qid = strip_any_prefix(wikidata.trim(), &WIKIDATA_PREFIXES).to_uppercase();
+        match qid.strip_prefix('Q') {
+            Some(digits) if is_digits(digits) => Ok(ExternalId::Wikidata(qid)),
+            _ => Err(invalid("Wikidata ID", &qid)),
+        }
+    }
+
+    pub fn namespace(&self) -> &'static str {
+        match self {
+            ExternalId::Doi(_) => "doi",
+            ExternalId::Orcid(_) => "orcid",
+            ExternalId::Ror(_) => "ror",
+            ExternalId::Pmid(_) => "pmid",
+            ExternalId::Pmcid(_) => "pmcid",
+            ExternalId::Issn(_) => "issn",
+            ExternalId::Mag(_) => "mag",
+            ExternalId::Wikidata(_) => "wikidata",
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        match self {
+            ExternalId::Doi(v)
+            | ExternalId::Orcid(v)
+            | ExternalId::Ror(v)
+            | ExternalId::Pmid(v)
+            | ExternalId::Pmcid(v)
+            | ExternalId::Issn(v)
+            | ExternalId::Mag(v)
+            | ExternalId::Wikidata(v) => v,
+        }
+    }
+}
+
+/// Renders the `namespace:value` form OpenAlex accepts in entity paths.
+impl fmt::Display for ExternalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.namespace(), self.value())
+    }
+}
+
+/// Parses an identifier from any common textual form: `namespace:value`,
+/// resolver URLs (`https://doi.org/...`, `https://orcid.org/...`, ...) or the
+/// bare value when its shape is unambiguous. Bare digit strings could be either
+/// a PMID or a MAG ID and must carry a namespace.
+impl FromStr for ExternalId {
+    type Err = OARSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let lower = s.to_lowercase();
+
+        if let Some((namespace, value)) = s.split_once(':') {
+            match namespace.to_lowercase().as_str() {
+                "doi" => return ExternalId::doi(value),
+                "orcid" => return ExternalId::orcid(value),
+                "ror" => return ExternalId::ror(value),
+                "pmid" => return ExternalId::pmid(value),
+                "pmcid" => return ExternalId::pmcid(value),
+                "issn" => return ExternalId::issn(value),
+                "mag" => return ExternalId::mag(value),
+                "wikidata" => return ExternalId::wikidata(value),
+                _ => {}
+            }
+        }
+
+        let has_prefix = |prefixes: &[&str]| prefixes.iter().any(|p| lower.starts_with(p));
+        if has_prefix(&DOI_PREFIXES) || lower.starts_with("10.") {
+            ExternalId::doi(s)
+        } else if has_prefix(&ORCID_PREFIXES) {
+            ExternalId::orcid(s)
+        } else if has_prefix(&ROR_PREFIXES) {
+            ExternalId::ror(s)
+        } else if has_prefix(&PMCID_PREFIXES) || lower.starts_with("pmc") {
+            ExternalId::pmcid(s)
+        } else if has_prefix(&PMID_PREFIXES) {
+            ExternalId::pmid(s)
+        } else if has_prefix(&ISSN_PREFIXES) {
+            ExternalId::issn(s)
+        } else if has_prefix(&WIKIDATA_PREFIXES) || lower.starts_with('q') {
+            ExternalId::wikidata(s)
+        } else if s.len() == 19 && s.matches('-').count() == 3 {
+            ExternalId::orcid(s)
+        } else if s.len() == 9 && s.as_bytes()[4] == b'-' {
+            ExternalId::issn(s)
+        } else if s.len() == 9 && s.starts_with('0') && !is_digits(s) {
+            ExternalId::ror(s)
+        } else {
+            Err(OARSError::InvalidId(format!(
+                "cannot tell which identifier scheme `{}` belongs to",
+                s
+            )))
+        }
+    }
+}
+
+/// The argument accepted by the `get_*_by_id` methods.
+///
+/// `I` is the OpenAlex ID type of the entity being fetched, so an ID of the
+/// wrong kind is rejected at compile time:
+///
+/// ```compile_fail
+/// # use oars::client::OARSClient;
+/// # use oars::ids::AuthorId;
+/// # use oars::traits::WorkAPIClient;
+/// # async fn f(client: OARSClient) {
+/// client.get_work_by_id(AuthorId::new(5023888391)).await;
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lookup<I> {
+    OpenAlex(I),
+    External(ExternalId),
+}
+
+// Characters that would end or corrupt a path segment. `/` stays literal
+// because OpenAlex expects DOIs as `doi:10.x/y`, not with the slash escaped.
+const PATH_VALUE: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+impl<I: fmt::Display> Lookup<I> {
+    pub(crate) fn path_segment(&self) -> String {
+        match self {
+            Lookup::OpenAlex(id) => id.to_string(),
+            Lookup::External(id) => format!(
+                "{}:{}",
+                id.namespace(),
+                utf8_percent_encode(id.value(), PATH_VALUE)
+            ),
+        }
+    }
+}
+
+impl<I> From<ExternalId> for Lookup<I> {
+    fn from(id: ExternalId) -> Self {
+        Lookup::External(id)
+    }
+}
+
+macro_rules! openalex_id {
+    ($name:ident, $variant:ident, $prefix:literal, $kind:literal) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(u64);
+
+        impl $name {
+            pub fn new(number: u64) -> Self {
+                $name(number)
+            }
+
+            pub fn number(&self) -> u64 {
+                self.0
+            }
+
+            pub fn url(&self) -> String {
+                format!("{}{}", OPENALEX_PREFIXES[0], self)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}{}", $prefix, self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = OARSError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s.parse::<OpenAlexId>()? {
+                    OpenAlexId::$variant(id) => Ok(id),
+                    other => Err(OARSError::InvalidId(format!(
+                        "`{}` is {} ID, not {} ID",
+                        s.trim(),
+                        other.kind(),
+                        $kind
+                    ))),
+                }
+            }
+        }
+
+        impl From<$name> for OpenAlexId {
+            fn from(id: $name) -> Self {
+                OpenAlexId::$variant(id)
+            }
+        }
+
+        impl TryFrom<OpenAlexId> for $name {
+            type Error = OARSError;
+
+            fn try_from(id: OpenAlexId) -> Result<Self, Self::Error> {
+                match id {
+                    OpenAlexId::$variant(id) => Ok(id),
+                    other => Err(OARSError::InvalidId(format!(
+                        "`{}` is {} ID, not {} ID",
+                        other,
+                        other.kind(),
+                        $kind
+                    ))),
+                }
+            }
+        }
+
+        impl From<$name> for Lookup<$name> {
+            fn from(id: $name) -> Self {
+                Lookup::OpenAlex(id)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.url())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer)?
+                    .parse()
+                    .map_err(de::Error::custom)
+            }
+        }
+    };
+}
+
+openalex_id!(WorkId, Work, "W", "a work");
+openalex_id!(AuthorId, Author, "A", "an author");
+openalex_id!(SourceId, Source, "S", "a source");
+openalex_id!(InstitutionId, Institution, "I", "an institution");
+openalex_id!(PublisherId, Publisher, "P", "a publisher");
+openalex_id!(FunderId, Funder, "F", "a funder");
+openalex_id!(TopicId, Topic, "T", "a topic");
+openalex_id!(ConceptId, Concept, "C", "a concept");
+
+/// An OpenAlex ID of any entity kind, told apart by its prefix letter.
+///
+/// Both `https://openalex.org/W2741809807` and `W2741809807` (in any case)
+/// parse to the same value. Serialized as the URL form OpenAlex returns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OpenAlexId {
+    Work(WorkId),
+    Author(AuthorId),
+    Source(SourceId),
+    Institution(InstitutionId),
+    Publisher(PublisherId),
+    Funder(FunderId),
+    Topic(TopicId),
+    Concept(ConceptId),
+}
+
+impl OpenAlexId {
+    pub fn number(&self) -> u64 {
+        match self {
+            OpenAlexId::Work(id) => id.number(),
+            OpenAlexId::Author(id) => id.number(),
+            OpenAlexId::Source(id) => id.number(),
+            OpenAlexId::Institution(id) => id.number(),
+            OpenAlexId::Publisher(id) => id.number(),
+            OpenAlexId::Funder(id) => id.number(),
+            OpenAlexId::Topic(id) => id.number(),
+            OpenAlexId::Concept(id) => id.number(),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("{}{}", OPENALEX_PREFIXES[0], self)
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            OpenAlexId::Work(_) => "a work",
+            OpenAlexId::Author(_) => "an author",
+            OpenAlexId::Source(_) => "a source",
+            OpenAlexId::Institution(_) => "an institution",
+            OpenAlexId::Publisher(_) => "a publisher",
+            OpenAlexId::Funder(_) => "a funder",
+            OpenAlexId::Topic(_) => "a topic",
+            OpenAlexId::Concept(_) => "a concept",
+        }
+    }
+}
+
+impl fmt::Display for OpenAlexId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpenAlexId::Work(id) => id.fmt(f),
+            OpenAlexId::Author(id) => id.fmt(f),
+            OpenAlexId::Source(id) => id.fmt(f),
+            OpenAlexId::Institution(id) => id.fmt(f),
+            OpenAlexId::Publisher(id) => id.fmt(f),
+            OpenAlexId::Funder(id) => id.fmt(f),
+            OpenAlexId::Topic(id) => id.fmt(f),
+            OpenAlexId::Concept(id) => id.fmt(f),
+        }
+    }
+}
+
+impl FromStr for OpenAlexId {
+    type Err = OARSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let short = strip_any_prefix(s.trim(), &OPENALEX_PREFIXES);
+        let mut chars = short.chars();
+        let prefix = chars.next().map(|c| c.to_ascii_uppercase());
+        let digits = chars.as_str();
+        let number = match digits.parse::<u64>() {
+            Ok(number) if is_digits(digits) => number,
+            _ => return Err(invalid("OpenAlex ID", s.trim())),
+        };
+        match prefix {
+            Some('W') => Ok(OpenAlexId::Work(WorkId(number))),
+            Some('A') => Ok(OpenAlexId::Author(AuthorId(number))),
+            Some('S') => Ok(OpenAlexId::Source(SourceId(number))),
+            Some('I') => Ok(OpenAlexId::Institution(InstitutionId(number))),
+            Some('P') => Ok(OpenAlexId::Publisher(PublisherId(number))),
+            Some('F') => Ok(OpenAlexId::Funder(FunderId(number))),
+            Some('T') => Ok(OpenAlexId::Topic(TopicId(number))),
+            Some('C') => Ok(OpenAlexId::Concept(ConceptId(number))),
+            _ => Err(invalid("OpenAlex ID", s.trim())),
+        }
+    }
+}
+
+impl Serialize for OpenAlexId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.url())
+    }
+}
+
+impl<'de> Deserialize<'de> for OpenAlexId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+const OPENALEX_PREFIXES: [&str; 2] = ["https://openalex.org/", "http://openalex.org/"];
+const DOI_PREFIXES: [&str; 4] = [
+    "https://doi.org/",
+    "http://doi.org/",
+    "https://dx.doi.org/",
+    "http://dx.doi.org/",
+];
+const ORCID_PREFIXES: [&str; 2] = ["https://orcid.org/", "http://orcid.org/"];
+const ROR_PREFIXES: [&str; 2] = ["https://ror.org/", "http://ror.org/"];
+const PMID_PREFIXES: [&str; 2] = [
+    "https://pubmed.ncbi.nlm.nih.gov/",
+    "http://pubmed.ncbi.nlm.nih.gov/",
+];
+const PMCID_PREFIXES: [&str; 2] = [
+    "https://www.ncbi.nlm.nih.gov/pmc/articles/",
+    "http://www.ncbi.nlm.nih.gov/pmc/articles/",
+];
+const ISSN_PREFIXES: [&str; 2] = [
+    "https://portal.issn.org/resource/issn/",
+    "http://portal.issn.org/resource/issn/",
+];
+const WIKIDATA_PREFIXES: [&str; 4] = [
+    "https://www.wikidata.org/wiki/",
+    "http://www.wikidata.org/wiki/",
+    "https://www.wikidata.org/entity/",
+    "http://www.wikidata.org/entity/",
+];
+
+fn strip_any_prefix<'a>(value: &'a str, prefixes: &[&str]) -> &'a str {
+    for prefix in prefixes {
+        if value.len() >= prefix.len()
+            && value.is_char_boundary(prefix.len())
+            && value[..prefix.len()].eq_ignore_ascii_case(prefix)
+        {
+            return &value[prefix.len()..];
+        }
+    }
+    value
+}
+
+fn is_digits(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_ascii_digit())
+}
+
+fn invalid(kind: &str, value: &str) -> OARSError {
+    OARSError::InvalidId(format!("`{}` is not a valid {}", value, kind))
+}
+
+// ISO 7064 MOD 11-2, as specified by ORCID.
+fn orcid_check_char(digits: &[char]) -> char {
+    let total = digits.iter().fold(0u32, |total, digit| {
+        (total + digit.to_digit(10).unwrap_or(0)) * 2
+    });
+    match (12 - total % 11) % 11 {
+        10 => 'X',
+        check => char::from_digit(check, 10).unwrap_or('?'),
+    }
+}
+
+fn issn_check_char(digits: &[char]) -> char {
+    let sum: u32 = digits
+        .iter()
+        .zip((2..=8).rev())
+        .map(|(digit, weight)| digit.to_digit(10).unwrap_or(0) * weight)
+        .sum();
+    match (11 - sum % 11) % 11 {
+        10 => 'X',
+        check => char::from_digit(check, 10).unwrap_or('?'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> ExternalId {
+        s.parse()
+            .unwrap_or_else(|err| panic!("failed to parse {}: {}", s, err))
+    }
+
+    #[test]
+    fn test_doi_forms_normalize() {
+        let expected = ExternalId::Doi("10.7717/peerj.4375".to_owned());
+        assert_eq!(parse("https://doi.org/10.7717/PEERJ.4375"), expected);
+        assert_eq!(parse("doi:10.7717/peerj.4375"), expected);
+        assert_eq!(parse("10.7717/peerj.4375"), expected);
+        assert_eq!(parse("http://dx.doi.org/10.7717/peerj.4375"), expected);
+        assert_eq!(expected.to_string(), "doi:10.7717/peerj.4375");
+        assert!(ExternalId::doi("11.1234/x").is_err());
+    }
+
+    #[test]
+    fn test_orcid_checksum() {
+        let expected = ExternalId::Orcid("0000-0002-1825-0097".to_owned());
+        assert_eq!(parse("https://orcid.org/0000-0002-1825-0097"), expected);
+        assert_eq!(parse("orcid:0000000218250097"), expected);
+        assert_eq!(parse("0000-0002-1825-0097"), expected);
+        assert_eq!(
+            parse("0000-0002-1694-233x"),
+            ExternalId::Orcid("0000-0002-1694-233X".to_owned())
+        );
+        assert!(ExternalId::orcid("0000-0002-1825-0098").is_err());
+        assert!(ExternalId::orcid("0000-0002-1825").is_err());
+    }
+
+    #[test]
+    fn test_issn_checksum() {
+        let expected = ExternalId::Issn("2167-8359".to_owned());
+        assert_eq!(parse("2167-8359"), expected);
+        assert_eq!(parse("issn:21678359"), expected);
+        assert_eq!(
+            parse("issn:0000-006x"),
+            ExternalId::Issn("0000-006X".to_owned())
+        );
+        assert!(ExternalId::issn("2167-8358").is_err());
+    }
+
+    #[test]
+    fn test_other_namespaces() {
+        assert_eq!(
+            parse("https://ror.org/04DKP9463"),
+            ExternalId::Ror("04dkp9463".to_owned())
+        );
+        assert_eq!(
+            parse("https://pubmed.ncbi.nlm.nih.gov/29456894"),
+            ExternalId::Pmid("29456894".to_owned())
+        );
+        assert_eq!(
+            parse("https://www.ncbi.nlm.nih.gov/pmc/articles/5815332"),
+            ExternalId::Pmcid("PMC5815332".to_owned())
+        );
+        assert_eq!(
+            parse("pmc5815332"),
+            ExternalId::Pmcid("PMC5815332".to_owned())
+        );
+        assert_eq!(
+            parse("mag:2741809807"),
+            ExternalId::Mag("2741809807".to_owned())
+        );
+        assert_eq!(
+            parse("https://www.wikidata.org/wiki/Q1726380"),
+            ExternalId::Wikidata("Q1726380".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_bare_digits_are_ambiguous() {
+        assert!(matches!(
+            "29456894".parse::<ExternalId>(),
+            Err(OARSError::InvalidId(_))
+        ));
+    }
+
+    #[test]
+    fn test_lookup_path_segment() {
+        assert_eq!(
+            Lookup::from(WorkId::new(2741809807)).path_segment(),
+            "W2741809807"
+        );
+        assert_eq!(
+            Lookup::<WorkId>::from(parse("https://doi.org/10.7717/peerj.4375")).path_segment(),
+            "doi:10.7717/peerj.4375"
+        );
+        assert_eq!(
+            Lookup::<WorkId>::from(parse(
+                "doi:10.1002/(SICI)1097-4636(199706)35:4<457::AID-JBM6>3.0.CO;2-#"
+            ))
+            .path_segment(),
+            "doi:10.1002/(sici)1097-4636(199706)35:4%3C457::aid-jbm6%3E3.0.co;2-%23"
+        );
+    }
+
+    #[test]
+    fn test_openalex_id_forms_compare_canonically() {
+        let short: WorkId = "W2741809807".parse().unwrap();
+        let url: WorkId = "https://openalex.org/W2741809807".parse().unwrap();
+        let lower: WorkId = "w2741809807".parse().unwrap();
+        assert_eq!(short, url);
+        assert_eq!(short, lower);
+        assert_eq!(short.to_string(), "W2741809807");
+        assert_eq!(short.url(), "https://openalex.org/W2741809807");
+        assert!("W27418x9807".parse::<WorkId>().is_err());
+        assert!("X2741809807".parse::<OpenAlexId>().is_err());
+    }
+
+    #[test]
+    fn test_openalex_id_kind_detection() {
+        let kinds: Vec<OpenAlexId> = ["W1", "A2", "S3", "I4", "P5", "F6", "T7", "C8"]
+            .iter()
+            .map(|id| id.parse().unwrap())
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                OpenAlexId::Work(WorkId::new(1)),
+                OpenAlexId::Author(AuthorId::new(2)),
+                OpenAlexId::Source(SourceId::new(3)),
+                OpenAlexId::Institution(InstitutionId::new(4)),
+                OpenAlexId::Publisher(PublisherId::new(5)),
+                OpenAlexId::Funder(FunderId::new(6)),
+                OpenAlexId::Topic(TopicId::new(7)),
+                OpenAlexId::Concept(ConceptId::new(8)),
+            ]
+        );
+        assert!(matches!(
+            "https://openalex.org/A5023888391".parse::<WorkId>(),
+            Err(OARSError::InvalidId(msg)) if msg.contains("an author ID")
+        ));
+        assert_eq!(
+            InstitutionId::try_from(kinds[3]).unwrap(),
+            InstitutionId::new(4)
+        );
+        assert!(WorkId::try_from(kinds[3]).is_err());
+    }
+
+    #[test]
+    fn test_openalex_id_serde_uses_url_form() {
+        let id: OpenAlexId = serde_json::from_str("\"P4310320595\"").unwrap();
+        assert_eq!(
+            serde_json::to_string(&id).unwrap(),
+            "\"https://openalex.org/P4310320595\""
+        );
+        let id: AuthorId = serde_json::from_str("\"https://openalex.org/A5023888391\"").unwrap();
+        assert_eq!(id, AuthorId::new(5023888391));
+        assert!(serde_json::from_str::<AuthorId>("\"W1\"").is_err());
+    }
+}
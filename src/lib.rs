@@ -1,6 +1,10 @@
+#[macro_use]
+mod macros;
+
 pub mod entities {
     pub mod author;
     pub mod common;
+    pub mod enums;
     pub mod funder;
     pub mod institution;
     pub mod publisher;
@@ -9,10 +13,23 @@ pub mod entities {
     pub mod work;
 }
 
+pub mod abstracts;
 pub mod bakery;
+pub mod blocking;
 pub mod client;
 pub mod config;
+pub mod dates;
+pub mod drift;
 pub mod error;
+pub mod filter;
+pub mod ids;
+pub mod query;
+pub mod quota;
+pub mod ratelimit;
+pub mod response;
+pub mod search;
+#[cfg(test)]
+mod test_support;
 pub mod traits;
 
 use std::sync::Once;
@@ -119,3 +136,72 @@ macro_rules! check_entity_against_json {
         assert_json_include!(actual: actual_value, expected: expected_value);
     }};
 }
+
+#[macro_export]
+macro_rules! entity_fields_complete {
+    ($entity:ty, $json_path:expr) => {{
+        use $crate::traits::Entity;
+
+        let json = std::fs::read_to_string($json_path).expect("Failed to read JSON file");
+        let original_value: serde_json::Value =
+            serde_json::from_str(&json).expect("Failed to parse the JSON into Value");
+        let entity: $entity = serde_json::from_str(&json).expect("Failed to deserialize the JSON");
+        let serialized_value =
+            serde_json::to_value(&entity).expect("Failed to serialize the entity");
+
+        let mut declared_fields: Vec<&str> = <$entity as Entity>::FIELDS.to_vec();
+        declared_fields.sort_unstable();
+        let mut original_fields: Vec<&str> = original_value
+            .as_object()
+            .expect("JSON is not an object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        original_fields.sort_unstable();
+        assert_eq!(declared_fields, original_fields);
+
+        for field in serialized_value
+            .as_object()
+            .expect("Entity did not serialize to a JSON object")
+            .keys()
+        {
+            assert!(
+                declared_fields.contains(&field.as_str()),
+                "serialized field `{}` is missing from FIELDS",
+                field
+            );
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! entity_round_trip_lossless {
+    ($entity:ty, $json_path:expr) => {{
+        use assert_json_diff::assert_json_eq;
+        use $crate::bakery::{Deflatable, Deflation, Leavenable};
+
+        let json = std::fs::read_to_string($json_path).expect("Failed to read JSON file");
+        let mut original_value: serde_json::Value =
+            serde_json::from_str(&json).expect("Failed to parse the JSON into Value");
+        original_value
+            .as_object_mut()
+            .expect("JSON is not an object")
+            .insert(
+                "x_unmodelled_field".to_owned(),
+                serde_json::json!({"nested": [1, "two", {"three": 3.5, "four": null}]}),
+            );
+
+        let entity: $entity = original_value
+            .to_string()
+            .leaven()
+            .expect("Failed to leaven the entity");
+        assert!(entity.extra().contains_key("x_unmodelled_field"));
+        let deflated_value = entity
+            .deflate(Deflation::ToJsonValue)
+            .expect("Failed to deflate entity into JSON Value")
+            .to_json()
+            .expect("Deflation to JSON Value failed");
+
+        assert_json_eq!(deflated_value, original_value);
+    }};
+}
@@ -0,0 +1,312 @@
+// Generates the read accessors of an entity or nested type from a list of its
+// fields.
+//
+// Fields are stored as `Option<Option<T>>` so that an explicit `null` survives
+// a round trip; getters read both `None` and `Some(None)` as absent. Each field
+// names the shape of its getter:
+//   str            String  -> Option<&str>
+//   copy<T>        T       -> Option<T>
+//   ref<T>         T       -> Option<&T>
+//   slice<T>       Vec<T>  -> &[T]
+//   id<T>          like copy, but the builder parses the ID from text
+//   id_list<T>     like slice, but the builder parses the IDs from text
+//   doi, orcid, ror
+//                  like str, but the builder validates the identifier
+// A field serialized under another name is written `field as "name"`.
+// The builder named after the type is generated from the same list.
+macro_rules! accessors {
+    ($entity:ident, $builder:ident {
+        $($field:ident $(as $wire:literal)? : $kind:ident $(<$ty:ty>)?),* $(,)?
+    }) => {
+        impl $entity {
+            $(accessors!(@getter $field $kind $($ty)?);)*
+        }
+
+        builder!($entity, $builder {
+            $($field $(as $wire)?: $kind $(<$ty>)?),*
+        });
+    };
+
+    (@getter $field:ident str) => {
+        pub fn $field(&self) -> Option<&str> {
+            self.$field.as_ref().and_then(|value| value.as_deref())
+        }
+    };
+    (@getter $field:ident copy $ty:ty) => {
+        pub fn $field(&self) -> Option<$ty> {
+            self.$field.flatten()
+        }
+    };
+    (@getter $field:ident ref $ty:ty) => {
+        pub fn $field(&self) -> Option<&$ty> {
+            self.$field.as_ref().and_then(Option::as_ref)
+        }
+    };
+    (@getter $field:ident slice $ty:ty) => {
+        pub fn $field(&self) -> &[$ty] {
+            self.$field
+                .as_ref()
+                .and_then(|value| value.as_deref())
+                .unwrap_or(&[])
+        }
+    };
+
+    (@getter $field:ident id $ty:ty) => {
+        accessors!(@getter $field copy $ty);
+    };
+    (@getter $field:ident id_list $ty:ty) => {
+        accessors!(@getter $field slice $ty);
+    };
+    (@getter $field:ident doi) => {
+        accessors!(@getter $field str);
+    };
+    (@getter $field:ident orcid) => {
+        accessors!(@getter $field str);
+    };
+    (@getter $field:ident ror) => {
+        accessors!(@getter $field str);
+    };
+
+    (@wire $field:ident) => {
+        stringify!($field)
+    };
+    (@wire $field:ident $wire:literal) => {
+        $wire
+    };
+}
+
+// Like `accessors!`, and also records the wire names of the listed fields so
+// that `accessors_complete!` can check them against `Entity::FIELDS`.
+macro_rules! entity_accessors {
+    ($entity:ident, $builder:ident {
+        $($field:ident $(as $wire:literal)? : $kind:ident $(<$ty:ty>)?),* $(,)?
+    }) => {
+        accessors!($entity, $builder {
+            $($field $(as $wire)?: $kind $(<$ty>)?),*
+        });
+
+        #[cfg(test)]
+        impl $entity {
+            pub(crate) const ACCESSORS: &'static [&'static str] =
+                &[$(accessors!(@wire $field $($wire)?)),*];
+        }
+    };
+}
+
+// Asserts that every field listed in `Entity::FIELDS` has an accessor.
+#[cfg(test)]
+macro_rules! accessors_complete {
+    ($entity:ty) => {{
+        use $crate::traits::Entity;
+
+        let mut fields = <$entity as Entity>::FIELDS.to_vec();
+        fields.sort_unstable();
+        let mut accessors = <$entity>::ACCESSORS.to_vec();
+        accessors.sort_unstable();
+        assert_eq!(fields, accessors);
+    }};
+}
+
+// Generates `$builder`, a consuming builder for `$target`, with one setter per
+// listed field (see `accessors!` for the field kinds). Identifiers are parsed
+// as they are set and the first invalid one is returned from `build()`.
+// `Unmodelled` is implemented from the same list, so every listed field is
+// searched for nested unknown fields.
+macro_rules! builder {
+    ($target:ident, $builder:ident {
+        $($field:ident $(as $wire:literal)? : $kind:ident $(<$ty:ty>)?),* $(,)?
+    }) => {
+        #[derive(Debug, Default)]
+        pub struct $builder {
+            inner: $target,
+            error: Option<$crate::error::OARSError>,
+        }
+
+        impl $builder {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            $(builder!(@setter $field $kind $($ty)?);)*
+
+            pub fn extra(mut self, extra: $crate::entities::common::Extra) -> Self {
+                self.inner.extra = extra;
+                self
+            }
+
+            pub fn build(self) -> Result<$target, $crate::error::OARSError> {
+                match self.error {
+                    Some(err) => Err(err),
+                    None => Ok(self.inner),
+                }
+            }
+        }
+
+        impl $target {
+            pub fn builder() -> $builder {
+                $builder::new()
+            }
+        }
+
+        impl $crate::entities::common::Unmodelled for $target {
+            fn visit_unmodelled(
+                &self,
+                path: &mut String,
+                visit: &mut dyn FnMut(&str, &serde_json::Value),
+            ) {
+                use $crate::entities::common::push_key;
+
+                $(
+                    let len = push_key(path, accessors!(@wire $field $($wire)?));
+                    self.$field.visit_unmodelled(path, visit);
+                    path.truncate(len);
+                )*
+                for (key, value) in self.extra.iter() {
+                    let len = push_key(path, key);
+                    visit(path, value);
+                    path.truncate(len);
+                }
+            }
+        }
+    };
+
+    (@setter $field:ident str) => {
+        pub fn $field(mut self, $field: impl Into<String>) -> Self {
+            self.inner.$field = Some(Some($field.into()));
+            self
+        }
+    };
+    (@setter $field:ident copy $ty:ty) => {
+        builder!(@setter $field ref $ty);
+    };
+    (@setter $field:ident ref $ty:ty) => {
+        pub fn $field(mut self, $field: $ty) -> Self {
+            self.inner.$field = Some(Some($field));
+            self
+        }
+    };
+    (@setter $field:ident slice $ty:ty) => {
+        pub fn $field<I: IntoIterator<Item = $ty>>(mut self, $field: I) -> Self {
+            self.inner.$field = Some(Some($field.into_iter().collect()));
+            self
+        }
+    };
+    (@setter $field:ident id $ty:ty) => {
+        pub fn $field<V: ToString>(mut self, $field: V) -> Self {
+            match $field.to_string().parse::<$ty>() {
+                Ok(id) => self.inner.$field = Some(Some(id)),
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                }
+            }
+            self
+        }
+    };
+    (@setter $field:ident id_list $ty:ty) => {
+        pub fn $field<I, V>(mut self, $field: I) -> Self
+        where
+            I: IntoIterator<Item = V>,
+            V: ToString,
+        {
+            let ids: Result<Vec<$ty>, _> = $field
+                .into_iter()
+                .map(|id| id.to_string().parse::<$ty>())
+                .collect();
+            match ids {
+                Ok(ids) => self.inner.$field = Some(Some(ids)),
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                }
+            }
+            self
+        }
+    };
+    (@setter $field:ident doi) => {
+        builder!(@external $field doi "https://doi.org/");
+    };
+    (@setter $field:ident orcid) => {
+        builder!(@external $field orcid "https://orcid.org/");
+    };
+    (@setter $field:ident ror) => {
+        builder!(@external $field ror "https://ror.org/");
+    };
+
+    // Stored in the resolver URL form OpenAlex uses for these fields.
+    (@external $field:ident $scheme:ident $resolver:literal) => {
+        pub fn $field(mut self, $field: &str) -> Self {
+            match $crate::ids::ExternalId::$scheme($field) {
+                Ok(id) => {
+                    self.inner.$field = Some(Some(format!("{}{}", $resolver, id.value())))
+                }
+                Err(err) => {
+                    self.error.get_or_insert(err);
+                }
+            }
+            self
+        }
+    };
+}
+
+// Defines an enum over the known values of a categorical string field. Values
+// that are not listed deserialize to `Other` and serialize back unchanged.
+// `Other` can only be built through `From`, so it never holds a listed value.
+macro_rules! string_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $wire:literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Other($crate::entities::enums::Unlisted),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $wire,)*
+                    $name::Other(other) => other.as_str(),
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                $name::from(value.to_owned())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($wire => $name::$variant,)*
+                    _ => $name::Other($crate::entities::enums::Unlisted(value)),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($name::from(s))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map($name::from)
+            }
+        }
+    };
+}
@@ -0,0 +1,335 @@
+use crate::error::OARSError;
+use crate::filter::Filter;
+use crate::search::Search;
+use reqwest::Url;
+use std::fmt;
+
+pub const MAX_PER_PAGE: usize = 200;
+
+/// Parameters shared by every list endpoint.
+///
+/// Parameters are always rendered in the same order, so two equal queries
+/// produce byte-identical URLs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Query {
+    filter: Filter,
+    search: Option<Search>,
+    sort: Vec<(String, bool)>,
+    per_page: Option<usize>,
+    page: Option<usize>,
+    sample: Option<usize>,
+    seed: Option<u64>,
+    select: Vec<String>,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    pub fn search(mut self, search: Search) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    pub fn sort(mut self, field: &str) -> Self {
+        self.sort.push((field.to_owned(), false));
+        self
+    }
+
+    pub fn sort_desc(mut self, field: &str) -> Self {
+        self.sort.push((field.to_owned(), true));
+        self
+    }
+
+    pub fn per_page(mut self, per_page: usize) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn sample(mut self, sample: usize) -> Self {
+        self.sample = Some(sample);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn select<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.select = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), OARSError> {
+        self.filter.validate()?;
+        if let Some(per_page) = self.per_page {
+            if !(1..=MAX_PER_PAGE).contains(&per_page) {
+                return Err(OARSError::InvalidQuery(format!(
+                    "per_page must be between 1 and {}, got {}",
+                    MAX_PER_PAGE, per_page
+                )));
+            }
+        }
+        if self.page == Some(0) {
+            return Err(OARSError::InvalidQuery(
+                "page numbers start at 1".to_owned(),
+            ));
+        }
+        if self.seed.is_some() && self.sample.is_none() {
+            return Err(OARSError::InvalidQuery(
+                "seed has no effect without sample".to_owned(),
+            ));
+        }
+        if self.select.iter().any(|field| field.is_empty()) {
+            return Err(OARSError::InvalidQuery(
+                "select contains an empty field name".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn validate_select(&self, fields: &[&str]) -> Result<(), OARSError> {
+        match self
+            .select
+            .iter()
+            .find(|field| !fields.contains(&field.as_str()))
+        {
+            Some(unknown) => Err(OARSError::InvalidQuery(format!(
+                "cannot select unknown field `{}`",
+                unknown
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn to_pairs(&self) -> Vec<(&'static str, String)> {
+        let mut pairs = Vec::new();
+        if !self.filter.is_empty() {
+            pairs.push(("filter", self.filter.to_string()));
+        }
+        if let Some(search) = &self.search {
+            pairs.push(("search", search.to_string()));
+        }
+        if !self.sort.is_empty() {
+            let keys: Vec<String> = self
+                .sort
+                .iter()
+                .map(|(field, desc)| {
+                    if *desc {
+                        format!("{}:desc", field)
+                    } else {
+                        field.clone()
+                    }
+                })
+                .collect();
+            pairs.push(("sort", keys.join(",")));
+        }
+        if let Some(per_page) = self.per_page {
+            pairs.push(("per_page", per_page.to_string()));
+        }
+        if let Some(page) = self.page {
+            pairs.push(("page", page.to_string()));
+        }
+        if let Some(sample) = self.sample {
+            pairs.push(("sample", sample.to_string()));
+        }
+        if let Some(seed) = self.seed {
+            pairs.push(("seed", seed.to_string()));
+        }
+        if !self.select.is_empty() {
+            pairs.push(("select", self.select.join(",")));
+        }
+        pairs
+    }
+
+    pub(crate) fn validate_group_by(&self) -> Result<(), OARSError> {
+        if !self.select.is_empty() || self.sample.is_some() {
+            return Err(OARSError::InvalidQuery(
+                "select and sample cannot be combined with group_by".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn cursor_query(&self) -> Result<Query, OARSError> {
+        if self.page.is_some() {
+            return Err(OARSError::InvalidQuery(
+                "page cannot be combined with cursor paging".to_owned(),
+            ));
+        }
+        Ok(Query {
+            per_page: self.per_page.or(Some(MAX_PER_PAGE)),
+            ..self.clone()
+        })
+    }
+
+    pub fn to_url(&self, basepath: &str, endpoint: &str) -> Result<String, OARSError> {
+        self.validate()?;
+        build_url(basepath, endpoint, &self.to_pairs()).map(String::from)
+    }
+}
+
+impl From<Filter> for Query {
+    fn from(filter: Filter) -> Self {
+        Query::new().filter(filter)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupBy {
+    field: String,
+    include_unknown: bool,
+}
+
+impl GroupBy {
+    pub fn new(field: &str) -> Self {
+        Self {
+            field: field.to_owned(),
+            include_unknown: false,
+        }
+    }
+
+    pub fn include_unknown(mut self) -> Self {
+        self.include_unknown = true;
+        self
+    }
+}
+
+impl fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.include_unknown {
+            write!(f, "{}:include_unknown", self.field)
+        } else {
+            f.write_str(&self.field)
+        }
+    }
+}
+
+pub(crate) fn build_url(
+    basepath: &str,
+    endpoint: &str,
+    pairs: &[(&str, String)],
+) -> Result<Url, OARSError> {
+    let mut url = Url::parse(&format!("{}/{}", basepath.trim_end_matches('/'), endpoint))
+        .map_err(|err| OARSError::InvalidQuery(format!("invalid basepath: {}", err)))?;
+    if !pairs.is_empty() {
+        url.query_pairs_mut().extend_pairs(pairs);
+    }
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::OARSConfig;
+
+    #[test]
+    fn test_empty_query_renders_bare_endpoint() {
+        let url = Query::new()
+            .to_url(&OARSConfig::new().basepath, "works")
+            .unwrap();
+        assert_eq!(url, "https://api.openalex.org/works");
+    }
+
+    #[test]
+    fn test_query_renders_in_fixed_order() {
+        let query = Query::new()
+            .select(["id", "doi"])
+            .seed(7)
+            .sample(50)
+            .page(2)
+            .per_page(100)
+            .sort_desc("cited_by_count")
+            .sort("publication_year")
+            .search(Search::terms("coral"))
+            .filter(Filter::new().equals("is_oa", true));
+        let url = query.to_url("https://api.openalex.org", "works").unwrap();
+        assert_eq!(
+            url,
+            "https://api.openalex.org/works?filter=is_oa%3Atrue&search=coral\
+             &sort=cited_by_count%3Adesc%2Cpublication_year&per_page=100&page=2\
+             &sample=50&seed=7&select=id%2Cdoi"
+        );
+    }
+
+    #[test]
+    fn test_identical_queries_produce_identical_urls() {
+        let build = || {
+            Query::new()
+                .filter(Filter::new().equals("publication_year", 2020))
+                .sort_desc("cited_by_count")
+                .per_page(25)
+        };
+        assert_eq!(
+            build()
+                .to_url("https://api.openalex.org", "authors")
+                .unwrap(),
+            build()
+                .to_url("https://api.openalex.org", "authors")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_per_page_is_validated() {
+        assert!(Query::new().per_page(0).validate().is_err());
+        assert!(Query::new().per_page(201).validate().is_err());
+        assert!(Query::new().per_page(1).validate().is_ok());
+        assert!(Query::new().per_page(200).validate().is_ok());
+    }
+
+    #[test]
+    fn test_select_is_validated_against_fields() {
+        let fields = ["id", "doi", "title", "cited_by_count"];
+        assert!(Query::new()
+            .select(["id", "doi", "cited_by_count"])
+            .validate_select(&fields)
+            .is_ok());
+        assert!(matches!(
+            Query::new().select(["id", "tilte"]).validate_select(&fields),
+            Err(OARSError::InvalidQuery(msg)) if msg.contains("tilte")
+        ));
+    }
+
+    #[test]
+    fn test_group_by_rendering() {
+        assert_eq!(GroupBy::new("oa_status").to_string(), "oa_status");
+        assert_eq!(
+            GroupBy::new("authorships.institutions.id")
+                .include_unknown()
+                .to_string(),
+            "authorships.institutions.id:include_unknown"
+        );
+    }
+
+    #[test]
+    fn test_filter_values_are_validated() {
+        let query = Query::from(Filter::new().equals("display_name", "Smith, J."));
+        assert!(matches!(
+            query.to_url("https://api.openalex.org", "authors"),
+            Err(OARSError::InvalidQuery(_))
+        ));
+    }
+
+    #[test]
+    fn test_seed_requires_sample() {
+        assert!(Query::new().seed(1).validate().is_err());
+        assert!(Query::new().sample(10).seed(1).validate().is_ok());
+    }
+}
@@ -0,0 +1,257 @@
+use crate::error::OARSError;
+use fs2::FileExt;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Index of the quota window containing `now`, for windows of `length`.
+///
+/// Windows are counted from the Unix epoch, so with the default length of one
+/// day they start at UTC midnight, as OpenAlex's daily limit does.
+pub fn window(now: SystemTime, length: Duration) -> u64 {
+    let elapsed = now.duration_since(UNIX_EPOCH).unwrap_or_default();
+    elapsed.as_secs() / length.as_secs().max(1)
+}
+
+/// Where a client keeps the number of queries it has made per quota window.
+///
+/// Implementations must make `consume` atomic with respect to every client
+/// sharing the store, so that they stay within one budget between them.
+pub trait QuotaStore: fmt::Debug + Send + Sync {
+    /// Queries used so far in `window`.
+    fn used(&self, window: u64) -> Result<usize, OARSError>;
+
+    /// Adds `increment` to the queries used in `window` and returns the new
+    /// total, or fails with `QueryLimitReached` if that would exceed `limit`.
+    fn consume(&self, window: u64, increment: usize, limit: usize) -> Result<usize, OARSError>;
+
+    /// Forgets the queries used in `window`.
+    fn reset(&self, window: u64) -> Result<(), OARSError>;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct Usage {
+    window: u64,
+    used: usize,
+}
+
+impl Usage {
+    fn used(&self, window: u64) -> usize {
+        if self.window == window {
+            self.used
+        } else {
+            0
+        }
+    }
+
+    fn consume(&mut self, window: u64, increment: usize, limit: usize) -> Result<usize, OARSError> {
+        let used = match self.used(window).checked_add(increment) {
+            Some(used) if used <= limit => used,
+            _ => return Err(OARSError::QueryLimitReached),
+        };
+        *self = Usage { window, used };
+        Ok(used)
+    }
+}
+
+/// Keeps the count in memory, shared by the clones of one client.
+#[derive(Debug, Default)]
+pub struct MemoryQuotaStore {
+    usage: Mutex<Usage>,
+}
+
+impl MemoryQuotaStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl QuotaStore for MemoryQuotaStore {
+    fn used(&self, window: u64) -> Result<usize, OARSError> {
+        Ok(self.usage.lock().unwrap().used(window))
+    }
+
+    fn consume(&self, window: u64, increment: usize, limit: usize) -> Result<usize, OARSError> {
+        self.usage.lock().unwrap().consume(window, increment, limit)
+    }
+
+    fn reset(&self, window: u64) -> Result<(), OARSError> {
+        *self.usage.lock().unwrap() = Usage { window, used: 0 };
+        Ok(())
+    }
+}
+
+/// Keeps the count in a file, locked while it is updated, so that restarts
+/// and separate processes pointed at the same path share one budget.
+#[derive(Debug)]
+pub struct FileQuotaStore {
+    path: PathBuf,
+}
+
+impl FileQuotaStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn with_usage<R>(
+        &self,
+        update: impl FnOnce(&mut Usage) -> Result<R, OARSError>,
+    ) -> Result<R, OARSError> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.path)
+            .map_err(OARSError::QuotaStore)?;
+        file.lock_exclusive().map_err(OARSError::QuotaStore)?;
+
+        let mut usage = read_usage(&mut file)?;
+        let before = usage;
+        let result = update(&mut usage)?;
+        if usage != before {
+            write_usage(&mut file, usage)?;
+        }
+        Ok(result)
+    }
+}
+
+impl QuotaStore for FileQuotaStore {
+    fn used(&self, window: u64) -> Result<usize, OARSError> {
+        self.with_usage(|usage| Ok(usage.used(window)))
+    }
+
+    fn consume(&self, window: u64, increment: usize, limit: usize) -> Result<usize, OARSError> {
+        self.with_usage(|usage| usage.consume(window, increment, limit))
+    }
+
+    fn reset(&self, window: u64) -> Result<(), OARSError> {
+        self.with_usage(|usage| {
+            *usage = Usage { window, used: 0 };
+            Ok(())
+        })
+    }
+}
+
+// The file holds `<window> <used>`; an empty one counts as unused. Anything
+// else is an error rather than a fresh budget, so a corrupted file cannot
+// hand every process sharing it a full day of queries.
+fn read_usage(file: &mut File) -> Result<Usage, OARSError> {
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .map_err(OARSError::QuotaStore)?;
+    let mut fields = contents.split_whitespace();
+    match (fields.next(), fields.next(), fields.next()) {
+        (None, _, _) => Ok(Usage::default()),
+        (Some(window), Some(used), None) => match (window.parse(), used.parse()) {
+            (Ok(window), Ok(used)) => Ok(Usage { window, used }),
+            _ => Err(corrupted(&contents)),
+        },
+        _ => Err(corrupted(&contents)),
+    }
+}
+
+fn corrupted(contents: &str) -> OARSError {
+    OARSError::QuotaStore(io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unreadable quota file contents {:?}", contents),
+    ))
+}
+
+fn write_usage(file: &mut File, usage: Usage) -> Result<(), OARSError> {
+    file.set_len(0)
+        .and_then(|_| file.seek(SeekFrom::Start(0)))
+        .and_then(|_| writeln!(file, "{} {}", usage.window, usage.used))
+        .and_then(|_| file.sync_data())
+        .map_err(OARSError::QuotaStore)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+    #[test]
+    fn test_windows_align_to_utc_days() {
+        let midnight = UNIX_EPOCH + Duration::from_secs(19_800 * 86_400);
+        assert_eq!(window(midnight, DAY), 19_800);
+        assert_eq!(window(midnight - Duration::from_secs(1), DAY), 19_799);
+    }
+
+    #[test]
+    fn test_memory_store_resets_with_window() {
+        let store = MemoryQuotaStore::new();
+        assert_eq!(store.consume(1, 3, 5).unwrap(), 3);
+        assert!(matches!(
+            store.consume(1, 3, 5),
+            Err(OARSError::QueryLimitReached)
+        ));
+        assert_eq!(store.used(1).unwrap(), 3);
+        assert_eq!(store.used(2).unwrap(), 0);
+        assert_eq!(store.consume(2, 5, 5).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_consume_does_not_overflow() {
+        let store = MemoryQuotaStore::new();
+        assert_eq!(store.consume(1, 3, usize::MAX).unwrap(), 3);
+        assert!(matches!(
+            store.consume(1, usize::MAX, usize::MAX),
+            Err(OARSError::QueryLimitReached)
+        ));
+        assert_eq!(store.used(1).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_file_store_is_shared_between_handles() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota");
+        let stores: Vec<Arc<FileQuotaStore>> = (0..4)
+            .map(|_| Arc::new(FileQuotaStore::new(&path)))
+            .collect();
+
+        let handles: Vec<_> = stores
+            .iter()
+            .cloned()
+            .map(|store| {
+                thread::spawn(move || (0..50).filter(|_| store.consume(7, 1, 150).is_ok()).count())
+            })
+            .collect();
+        let granted: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+
+        assert_eq!(granted, 150);
+        let reopened = FileQuotaStore::new(&path);
+        assert_eq!(reopened.used(7).unwrap(), 150);
+        assert_eq!(reopened.used(8).unwrap(), 0);
+        reopened.reset(7).unwrap();
+        assert_eq!(stores[0].used(7).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_file_store_rejects_corrupted_contents() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota");
+        let store = FileQuotaStore::new(&path);
+
+        std::fs::write(&path, "").unwrap();
+        assert_eq!(store.used(7).unwrap(), 0);
+        for contents in ["7", "7 1x", "7 10 3", "\0\0\0"] {
+            std::fs::write(&path, contents).unwrap();
+            assert!(matches!(
+                store.consume(7, 1, 150),
+                Err(OARSError::QuotaStore(err)) if err.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "\0\0\0");
+    }
+}
@@ -0,0 +1,136 @@
+use std::sync::Mutex;
+use tokio::time::{sleep, Duration, Instant};
+
+// The rate never adapts below this share of the configured one, so a burst of
+// 429s cannot stall the client for good.
+const MIN_RATE_FACTOR: f64 = 0.05;
+// Share of the configured rate given back after each successful response.
+const RECOVERY_FACTOR: f64 = 0.05;
+
+/// Token bucket holding requests to `persecond` on average with bursts of up
+/// to `burstsize`. Callers reserve a token before each request and wait out
+/// the returned delay, so concurrent requests are spread out in arrival order.
+#[derive(Debug)]
+pub struct RateLimiter {
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    configured: f64,
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl RateLimiter {
+    /// A `persecond` of zero or less disables limiting.
+    pub fn new(persecond: f64, burstsize: usize) -> Self {
+        let burst = burstsize.max(1) as f64;
+        Self {
+            bucket: Mutex::new(Bucket {
+                configured: persecond,
+                rate: persecond,
+                burst,
+                tokens: burst,
+                refilled: Instant::now(),
+            }),
+        }
+    }
+
+    /// Current rate in requests per second, after any adaptation.
+    pub fn rate(&self) -> f64 {
+        self.bucket.lock().unwrap().rate
+    }
+
+    /// Takes a token, waiting until it is available, and returns the time spent waiting.
+    pub async fn acquire(&self) -> Duration {
+        let delay = self.reserve();
+        if !delay.is_zero() {
+            sleep(delay).await;
+        }
+        delay
+    }
+
+    fn reserve(&self) -> Duration {
+        let mut bucket = self.bucket.lock().unwrap();
+        if bucket.configured <= 0.0 {
+            return Duration::ZERO;
+        }
+        bucket.refill();
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / bucket.rate)
+        }
+    }
+
+    /// Halves the rate after the server answered 429.
+    pub(crate) fn throttle(&self) {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill();
+        bucket.rate = (bucket.rate / 2.0).max(bucket.configured * MIN_RATE_FACTOR);
+        bucket.tokens = bucket.tokens.min(0.0);
+    }
+
+    /// Moves the rate back towards the configured one after a success.
+    pub(crate) fn recover(&self) {
+        let mut bucket = self.bucket.lock().unwrap();
+        if bucket.rate < bucket.configured {
+            bucket.refill();
+            bucket.rate =
+                (bucket.rate + bucket.configured * RECOVERY_FACTOR).min(bucket.configured);
+        }
+    }
+}
+
+impl Bucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.refilled = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bursts_then_spaces_requests() {
+        let limiter = RateLimiter::new(10.0, 3);
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve(), Duration::ZERO);
+        }
+        let first = limiter.reserve();
+        let second = limiter.reserve();
+        assert!(first > Duration::from_millis(80) && first <= Duration::from_millis(100));
+        assert!(second > Duration::from_millis(180) && second <= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_throttle_adapts_and_recovers() {
+        let limiter = RateLimiter::new(10.0, 1);
+        limiter.throttle();
+        assert_eq!(limiter.rate(), 5.0);
+        for _ in 0..10 {
+            limiter.throttle();
+        }
+        assert_eq!(limiter.rate(), 0.5);
+        for _ in 0..100 {
+            limiter.recover();
+        }
+        assert_eq!(limiter.rate(), 10.0);
+    }
+
+    #[test]
+    fn test_non_positive_rate_disables_limiting() {
+        let limiter = RateLimiter::new(0.0, 1);
+        for _ in 0..100 {
+            assert_eq!(limiter.reserve(), Duration::ZERO);
+        }
+    }
+}
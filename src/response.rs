@@ -0,0 +1,104 @@
+use crate::entities::common::Count;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Meta {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<Count>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub db_response_time_ms: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups_count: Option<Count>,
+}
+
+impl Meta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ListResult<T> {
+    pub meta: Meta,
+    pub results: Vec<T>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Group {
+    #[serde(default, deserialize_with = "deserialize_group_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_group_key")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<Count>,
+}
+
+impl Group {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+// Group keys come back as strings for most attributes but as bare numbers or
+// booleans for some (e.g. `publication_year`, `is_oa`).
+fn deserialize_group_key<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => None,
+        Some(Value::String(key)) => Some(key),
+        Some(other) => Some(other.to_string()),
+    })
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GroupByResult {
+    pub meta: Meta,
+    pub group_by: Vec<Group>,
+}
+
+/// Records fetched by a batch ID lookup.
+///
+/// `results` has one entry per requested ID, in input order and including
+/// duplicates, paired with the record found for it. An ID that OpenAlex merged
+/// into another record is answered with that record and listed in `merged`,
+/// mapped to the record's ID; `missing` holds the IDs nothing was found for.
+#[derive(Debug)]
+pub struct BatchResult<T, I> {
+    pub results: Vec<(I, Option<T>)>,
+    pub missing: Vec<I>,
+    pub merged: HashMap<I, I>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_result_keys() {
+        let json = r#"{
+            "meta": {"count": 3, "groups_count": 3},
+            "results": [],
+            "group_by": [
+                {"key": "gold", "key_display_name": "gold", "count": 10},
+                {"key": 2020, "key_display_name": "2020", "count": 4},
+                {"key": true, "key_display_name": "true", "count": 1}
+            ]
+        }"#;
+        let result: GroupByResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.meta.groups_count, Some(3));
+        let keys: Vec<_> = result.group_by.iter().map(|g| g.key.as_deref()).collect();
+        assert_eq!(keys, vec![Some("gold"), Some("2020"), Some("true")]);
+    }
+}
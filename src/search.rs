@@ -0,0 +1,190 @@
+use std::fmt;
+
+/// Full-text search expression for the `search=` parameter and `*.search` filters.
+///
+/// OpenAlex silently drops the `*`, `?` and `~` wildcard characters, so they are
+/// stripped from terms and phrases up front; uppercase `AND`/`OR`/`NOT` inside plain
+/// terms are lowercased so that only the builder methods produce boolean operators.
+/// Use [`Search::raw`] to send a hand-written query untouched.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Search {
+    Terms(String),
+    Phrase(String),
+    Raw(String),
+    And(Vec<Search>),
+    Or(Vec<Search>),
+    Not(Box<Search>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchField {
+    Default,
+    DisplayName,
+    Title,
+    Abstract,
+    TitleAndAbstract,
+    Fulltext,
+}
+
+impl SearchField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchField::Default => "default",
+            SearchField::DisplayName => "display_name",
+            SearchField::Title => "title",
+            SearchField::Abstract => "abstract",
+            SearchField::TitleAndAbstract => "title_and_abstract",
+            SearchField::Fulltext => "fulltext",
+        }
+    }
+}
+
+impl Search {
+    pub fn terms(terms: &str) -> Self {
+        Search::Terms(terms.to_owned())
+    }
+
+    pub fn phrase(phrase: &str) -> Self {
+        Search::Phrase(phrase.to_owned())
+    }
+
+    pub fn raw(query: &str) -> Self {
+        Search::Raw(query.to_owned())
+    }
+
+    pub fn and(self, other: Search) -> Self {
+        match self {
+            Search::And(mut operands) => {
+                operands.push(other);
+                Search::And(operands)
+            }
+            operand => Search::And(vec![operand, other]),
+        }
+    }
+
+    pub fn or(self, other: Search) -> Self {
+        match self {
+            Search::Or(mut operands) => {
+                operands.push(other);
+                Search::Or(operands)
+            }
+            operand => Search::Or(vec![operand, other]),
+        }
+    }
+
+    pub fn and_not(self, other: Search) -> Self {
+        self.and(Search::Not(Box::new(other)))
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, Search::And(_) | Search::Or(_))
+    }
+
+    fn write_operand(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_compound() {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+fn clean_word(word: &str) -> String {
+    let word: String = word
+        .chars()
+        .filter(|c| !matches!(c, '*' | '?' | '~' | '"'))
+        .collect();
+    match word.as_str() {
+        "AND" | "OR" | "NOT" => word.to_lowercase(),
+        _ => word,
+    }
+}
+
+fn clean_words(text: &str) -> String {
+    text.split_whitespace()
+        .map(clean_word)
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl fmt::Display for Search {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Search::Terms(terms) => f.write_str(&clean_words(terms)),
+            Search::Phrase(phrase) => write!(f, "\"{}\"", clean_words(phrase)),
+            Search::Raw(query) => f.write_str(query),
+            Search::And(operands) => {
+                for (i, operand) in operands.iter().enumerate() {
+                    match operand {
+                        Search::Not(negated) if i > 0 => {
+                            f.write_str(" NOT ")?;
+                            negated.write_operand(f)?;
+                        }
+                        _ => {
+                            if i > 0 {
+                                f.write_str(" AND ")?;
+                            }
+                            operand.write_operand(f)?;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            Search::Or(operands) => {
+                for (i, operand) in operands.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" OR ")?;
+                    }
+                    operand.write_operand(f)?;
+                }
+                Ok(())
+            }
+            Search::Not(negated) => {
+                f.write_str("NOT ")?;
+                negated.write_operand(f)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terms_strip_wildcards_and_operators() {
+        let search = Search::terms("  neuro* plasticity? AND  memory~ ");
+        assert_eq!(search.to_string(), "neuro plasticity and memory");
+    }
+
+    #[test]
+    fn test_phrase_is_quoted() {
+        let search = Search::phrase("sea \"level\" rise");
+        assert_eq!(search.to_string(), "\"sea level rise\"");
+    }
+
+    #[test]
+    fn test_boolean_composition() {
+        let search = Search::phrase("sesame street")
+            .and(Search::terms("elmo"))
+            .and_not(Search::terms("cookie").or(Search::terms("monster")));
+        assert_eq!(
+            search.to_string(),
+            "\"sesame street\" AND elmo NOT (cookie OR monster)"
+        );
+    }
+
+    #[test]
+    fn test_nested_groups_are_parenthesized() {
+        let search = Search::terms("dna")
+            .or(Search::terms("rna"))
+            .and(Search::phrase("gene expression"));
+        assert_eq!(search.to_string(), "(dna OR rna) AND \"gene expression\"");
+    }
+
+    #[test]
+    fn test_raw_is_untouched() {
+        assert_eq!(Search::raw("cell* AND x~").to_string(), "cell* AND x~");
+    }
+}
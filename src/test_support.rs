@@ -0,0 +1,21 @@
+// Response bodies shared by the client tests.
+
+pub(crate) fn list_body(entity_path: &str) -> String {
+    let entity = std::fs::read_to_string(entity_path).expect("Failed to read entity JSON file");
+    format!(
+        r#"{{"meta":{{"count":1,"db_response_time_ms":12,"page":1,"per_page":25}},"results":[{}]}}"#,
+        entity
+    )
+}
+
+pub(crate) fn cursor_body(entity_path: &str, next_cursor: Option<&str>) -> String {
+    let entity = std::fs::read_to_string(entity_path).expect("Failed to read entity JSON file");
+    let next_cursor = match next_cursor {
+        Some(cursor) => format!("\"{}\"", cursor),
+        None => "null".to_owned(),
+    };
+    format!(
+        r#"{{"meta":{{"count":2,"per_page":200,"next_cursor":{}}},"results":[{}]}}"#,
+        next_cursor, entity
+    )
+}
@@ -1,41 +1,296 @@
+use crate::blocking::BlockingIter;
+use crate::entities::common::Unmodelled;
 use crate::entities::{
     author::Author, funder::Funder, institution::Institution, publisher::Publisher, source::Source,
     topic::Topic, work::Work,
 };
 use crate::error::OARSError;
+use crate::ids::{
+    AuthorId, FunderId, InstitutionId, Lookup, PublisherId, SourceId, TopicId, WorkId,
+};
+use crate::query::{GroupBy, Query};
+use crate::response::{BatchResult, Group, GroupByResult, ListResult};
 use async_trait::async_trait;
+use futures::stream::BoxStream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Display;
+use std::hash::Hash;
+
+pub trait Entity: Clone + DeserializeOwned + Serialize + Unmodelled + Send + 'static {
+    const ENDPOINT: &'static str;
+    const FIELDS: &'static [&'static str];
+    const EXTERNAL_NAMESPACES: &'static [&'static str];
+
+    type Id: Copy + Eq + Hash + Display + Send + Sync + 'static;
+
+    fn openalex_id(&self) -> Option<&Self::Id>;
+}
 
 #[async_trait]
 pub trait AuthorAPIClient {
-    async fn get_author_by_id(&self, author_id: &str) -> Result<Author, OARSError>;
+    async fn get_author_by_id<I>(&self, author_id: I) -> Result<Author, OARSError>
+    where
+        I: Into<Lookup<AuthorId>> + Send;
+    async fn get_authors_by_ids(
+        &self,
+        author_ids: &[AuthorId],
+    ) -> Result<BatchResult<Author, AuthorId>, OARSError>;
+    async fn list_authors(&self, query: &Query) -> Result<ListResult<Author>, OARSError>;
+    fn stream_authors(&self, query: &Query) -> BoxStream<'static, Result<Author, OARSError>>;
+    async fn group_authors(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_author_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>>;
 }
 
 #[async_trait]
 pub trait FunderAPIClient {
-    async fn get_funder_by_id(&self, funder_id: &str) -> Result<Funder, OARSError>;
+    async fn get_funder_by_id<I>(&self, funder_id: I) -> Result<Funder, OARSError>
+    where
+        I: Into<Lookup<FunderId>> + Send;
+    async fn get_funders_by_ids(
+        &self,
+        funder_ids: &[FunderId],
+    ) -> Result<BatchResult<Funder, FunderId>, OARSError>;
+    async fn list_funders(&self, query: &Query) -> Result<ListResult<Funder>, OARSError>;
+    fn stream_funders(&self, query: &Query) -> BoxStream<'static, Result<Funder, OARSError>>;
+    async fn group_funders(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_funder_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>>;
 }
 
 #[async_trait]
 pub trait InstitutionAPIClient {
-    async fn get_institution_by_id(&self, institution_id: &str) -> Result<Institution, OARSError>;
+    async fn get_institution_by_id<I>(&self, institution_id: I) -> Result<Institution, OARSError>
+    where
+        I: Into<Lookup<InstitutionId>> + Send;
+    async fn get_institutions_by_ids(
+        &self,
+        institution_ids: &[InstitutionId],
+    ) -> Result<BatchResult<Institution, InstitutionId>, OARSError>;
+    async fn list_institutions(&self, query: &Query) -> Result<ListResult<Institution>, OARSError>;
+    fn stream_institutions(
+        &self,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Institution, OARSError>>;
+    async fn group_institutions(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_institution_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>>;
 }
 
 #[async_trait]
 pub trait PublisherAPIClient {
-    async fn get_publisher_by_id(&self, publisher_id: &str) -> Result<Publisher, OARSError>;
+    async fn get_publisher_by_id<I>(&self, publisher_id: I) -> Result<Publisher, OARSError>
+    where
+        I: Into<Lookup<PublisherId>> + Send;
+    async fn get_publishers_by_ids(
+        &self,
+        publisher_ids: &[PublisherId],
+    ) -> Result<BatchResult<Publisher, PublisherId>, OARSError>;
+    async fn list_publishers(&self, query: &Query) -> Result<ListResult<Publisher>, OARSError>;
+    fn stream_publishers(&self, query: &Query) -> BoxStream<'static, Result<Publisher, OARSError>>;
+    async fn group_publishers(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_publisher_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>>;
 }
 
 #[async_trait]
 pub trait SourceAPIClient {
-    async fn get_source_by_id(&self, source_id: &str) -> Result<Source, OARSError>;
+    async fn get_source_by_id<I>(&self, source_id: I) -> Result<Source, OARSError>
+    where
+        I: Into<Lookup<SourceId>> + Send;
+    async fn get_sources_by_ids(
+        &self,
+        source_ids: &[SourceId],
+    ) -> Result<BatchResult<Source, SourceId>, OARSError>;
+    async fn list_sources(&self, query: &Query) -> Result<ListResult<Source>, OARSError>;
+    fn stream_sources(&self, query: &Query) -> BoxStream<'static, Result<Source, OARSError>>;
+    async fn group_sources(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_source_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>>;
 }
 
 #[async_trait]
 pub trait TopicAPIClient {
-    async fn get_topic_by_id(&self, topic_id: &str) -> Result<Topic, OARSError>;
+    async fn get_topic_by_id<I>(&self, topic_id: I) -> Result<Topic, OARSError>
+    where
+        I: Into<Lookup<TopicId>> + Send;
+    async fn get_topics_by_ids(
+        &self,
+        topic_ids: &[TopicId],
+    ) -> Result<BatchResult<Topic, TopicId>, OARSError>;
+    async fn list_topics(&self, query: &Query) -> Result<ListResult<Topic>, OARSError>;
+    fn stream_topics(&self, query: &Query) -> BoxStream<'static, Result<Topic, OARSError>>;
+    async fn group_topics(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_topic_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>>;
 }
 
 #[async_trait]
 pub trait WorkAPIClient {
-    async fn get_work_by_id(&self, work_id: &str) -> Result<Work, OARSError>;
+    async fn get_work_by_id<I>(&self, work_id: I) -> Result<Work, OARSError>
+    where
+        I: Into<Lookup<WorkId>> + Send;
+    async fn get_works_by_ids(
+        &self,
+        work_ids: &[WorkId],
+    ) -> Result<BatchResult<Work, WorkId>, OARSError>;
+    async fn list_works(&self, query: &Query) -> Result<ListResult<Work>, OARSError>;
+    fn stream_works(&self, query: &Query) -> BoxStream<'static, Result<Work, OARSError>>;
+    async fn group_works(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_work_groups(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> BoxStream<'static, Result<Group, OARSError>>;
+}
+
+pub trait BlockingAuthorAPIClient {
+    fn get_author_by_id<I>(&self, author_id: I) -> Result<Author, OARSError>
+    where
+        I: Into<Lookup<AuthorId>>;
+    fn get_authors_by_ids(
+        &self,
+        author_ids: &[AuthorId],
+    ) -> Result<BatchResult<Author, AuthorId>, OARSError>;
+    fn list_authors(&self, query: &Query) -> Result<ListResult<Author>, OARSError>;
+    fn stream_authors(&self, query: &Query) -> BlockingIter<Author>;
+    fn group_authors(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError>;
+    fn stream_author_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group>;
+}
+
+pub trait BlockingFunderAPIClient {
+    fn get_funder_by_id<I>(&self, funder_id: I) -> Result<Funder, OARSError>
+    where
+        I: Into<Lookup<FunderId>>;
+    fn get_funders_by_ids(
+        &self,
+        funder_ids: &[FunderId],
+    ) -> Result<BatchResult<Funder, FunderId>, OARSError>;
+    fn list_funders(&self, query: &Query) -> Result<ListResult<Funder>, OARSError>;
+    fn stream_funders(&self, query: &Query) -> BlockingIter<Funder>;
+    fn group_funders(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError>;
+    fn stream_funder_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group>;
+}
+
+pub trait BlockingInstitutionAPIClient {
+    fn get_institution_by_id<I>(&self, institution_id: I) -> Result<Institution, OARSError>
+    where
+        I: Into<Lookup<InstitutionId>>;
+    fn get_institutions_by_ids(
+        &self,
+        institution_ids: &[InstitutionId],
+    ) -> Result<BatchResult<Institution, InstitutionId>, OARSError>;
+    fn list_institutions(&self, query: &Query) -> Result<ListResult<Institution>, OARSError>;
+    fn stream_institutions(&self, query: &Query) -> BlockingIter<Institution>;
+    fn group_institutions(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_institution_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group>;
+}
+
+pub trait BlockingPublisherAPIClient {
+    fn get_publisher_by_id<I>(&self, publisher_id: I) -> Result<Publisher, OARSError>
+    where
+        I: Into<Lookup<PublisherId>>;
+    fn get_publishers_by_ids(
+        &self,
+        publisher_ids: &[PublisherId],
+    ) -> Result<BatchResult<Publisher, PublisherId>, OARSError>;
+    fn list_publishers(&self, query: &Query) -> Result<ListResult<Publisher>, OARSError>;
+    fn stream_publishers(&self, query: &Query) -> BlockingIter<Publisher>;
+    fn group_publishers(
+        &self,
+        group_by: &GroupBy,
+        query: &Query,
+    ) -> Result<GroupByResult, OARSError>;
+    fn stream_publisher_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group>;
+}
+
+pub trait BlockingSourceAPIClient {
+    fn get_source_by_id<I>(&self, source_id: I) -> Result<Source, OARSError>
+    where
+        I: Into<Lookup<SourceId>>;
+    fn get_sources_by_ids(
+        &self,
+        source_ids: &[SourceId],
+    ) -> Result<BatchResult<Source, SourceId>, OARSError>;
+    fn list_sources(&self, query: &Query) -> Result<ListResult<Source>, OARSError>;
+    fn stream_sources(&self, query: &Query) -> BlockingIter<Source>;
+    fn group_sources(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError>;
+    fn stream_source_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group>;
+}
+
+pub trait BlockingTopicAPIClient {
+    fn get_topic_by_id<I>(&self, topic_id: I) -> Result<Topic, OARSError>
+    where
+        I: Into<Lookup<TopicId>>;
+    fn get_topics_by_ids(
+        &self,
+        topic_ids: &[TopicId],
+    ) -> Result<BatchResult<Topic, TopicId>, OARSError>;
+    fn list_topics(&self, query: &Query) -> Result<ListResult<Topic>, OARSError>;
+    fn stream_topics(&self, query: &Query) -> BlockingIter<Topic>;
+    fn group_topics(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError>;
+    fn stream_topic_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group>;
+}
+
+pub trait BlockingWorkAPIClient {
+    fn get_work_by_id<I>(&self, work_id: I) -> Result<Work, OARSError>
+    where
+        I: Into<Lookup<WorkId>>;
+    fn get_works_by_ids(&self, work_ids: &[WorkId])
+        -> Result<BatchResult<Work, WorkId>, OARSError>;
+    fn list_works(&self, query: &Query) -> Result<ListResult<Work>, OARSError>;
+    fn stream_works(&self, query: &Query) -> BlockingIter<Work>;
+    fn group_works(&self, group_by: &GroupBy, query: &Query) -> Result<GroupByResult, OARSError>;
+    fn stream_work_groups(&self, group_by: &GroupBy, query: &Query) -> BlockingIter<Group>;
 }